@@ -17,13 +17,10 @@
 use std::collections::HashMap;
 
 use edne::models::{
-    AddressId, BigUserId, CpcId, LocalityId, NeighborhoodId,
+    AddressId, BigUserId, Cep, CpcId, LocalityId, NeighborhoodId,
     OperationalUnitId, Uf,
 };
 
-/// CEP type (8 digits)
-pub type Cep = String;
-
 /// Complete information for a CEP
 #[derive(Debug, Clone)]
 pub struct CepInfo {
@@ -63,20 +60,16 @@ impl CepLookup {
         self.ceps.len()
     }
 
-    pub fn is_empty(&self) -> bool {
-        self.ceps.is_empty()
-    }
-
-    pub fn get(&self, cep: &str) -> Option<&CepInfo> {
-        self.ceps.get(cep)
+    pub fn get(&self, cep: Cep) -> Option<&CepInfo> {
+        self.ceps.get(&cep)
     }
 
     pub fn insert(&mut self, info: CepInfo) {
-        self.ceps.insert(info.cep.clone(), info);
+        self.ceps.insert(info.cep, info);
     }
 
     /// Search by CEP following the Correios algorithm
-    pub fn lookup(&self, cep: &str) -> Option<&CepInfo> {
+    pub fn lookup(&self, cep: Cep) -> Option<&CepInfo> {
         self.get(cep)
     }
 
@@ -84,11 +77,6 @@ impl CepLookup {
     pub fn by_uf(&self, uf: Uf) -> Vec<&CepInfo> {
         self.ceps.values().filter(|info| info.uf == uf).collect()
     }
-
-    /// Returns all CEPs for a locality
-    pub fn by_locality(&self, locality: &str) -> Vec<&CepInfo> {
-        self.ceps.values().filter(|info| info.locality == locality).collect()
-    }
 }
 
 /// Builder to construct CepLookup from eDNE data
@@ -177,7 +165,7 @@ impl CepLookupBuilder {
 
         // 1. Uncoded localities (general CEP)
         for locality in self.localities.values() {
-            if let Some(cep) = &locality.cep {
+            if let Some(cep) = locality.cep {
                 let neighborhood =
                     if let Some(sub_id) = locality.subordinate_to {
                         self.localities.get(&sub_id).map(|l| l.name.clone())
@@ -186,7 +174,7 @@ impl CepLookupBuilder {
                     };
 
                 lookup.insert(CepInfo {
-                    cep: cep.clone(),
+                    cep,
                     uf: locality.uf,
                     locality: locality.name.clone(),
                     neighborhood,
@@ -219,7 +207,7 @@ impl CepLookupBuilder {
             };
 
             lookup.insert(CepInfo {
-                cep: address.cep.clone(),
+                cep: address.cep,
                 uf: address.uf,
                 locality,
                 neighborhood,
@@ -243,7 +231,7 @@ impl CepLookupBuilder {
                 .map(|n| n.name.clone());
 
             lookup.insert(CepInfo {
-                cep: user.cep.clone(),
+                cep: user.cep,
                 uf: user.uf,
                 locality,
                 neighborhood,
@@ -267,7 +255,7 @@ impl CepLookupBuilder {
                 .map(|n| n.name.clone());
 
             lookup.insert(CepInfo {
-                cep: unit.cep.clone(),
+                cep: unit.cep,
                 uf: unit.uf,
                 locality,
                 neighborhood,
@@ -286,7 +274,7 @@ impl CepLookupBuilder {
                 .unwrap_or_default();
 
             lookup.insert(CepInfo {
-                cep: cpc.cep.clone(),
+                cep: cpc.cep,
                 uf: cpc.uf,
                 locality,
                 neighborhood: None,
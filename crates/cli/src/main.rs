@@ -17,12 +17,19 @@
 mod cep_lookup;
 
 use cep_lookup::{CepInfo, CepLookupBuilder, CepType};
+use edne::models::Cep;
 use edne::parser::{
-    addresses::Addresses, big_users::BigUsers, cpcs::Cpcs,
-    localities::Localities, neighborhoods::Neighborhoods,
+    Collection, addresses::Addresses, archive::Archive, big_users::BigUsers,
+    cpcs::Cpcs, localities::Localities, neighborhoods::Neighborhoods,
     operational_units::OperationalUnits,
 };
-use std::{env, fs, path::Path, process};
+use std::{
+    env,
+    fs::File,
+    io::{self, Read},
+    path::Path,
+    process,
+};
 
 enum Command {
     Parse(FileType, String),
@@ -44,13 +51,13 @@ fn print_usage(program: &str) {
     eprintln!();
     eprintln!("Commands:");
     eprintln!("  Parse single file:");
-    eprintln!("    {} <type> <path-to-file>", program);
+    eprintln!("    {} <type> <path-to-file | ->", program);
     eprintln!();
     eprintln!("  Build CEP lookup index:");
-    eprintln!("    {} build-index <data-directory>", program);
+    eprintln!("    {} build-index <data-directory | eDNE.zip>", program);
     eprintln!();
     eprintln!("  Lookup CEP:");
-    eprintln!("    {} lookup <data-directory> <cep>", program);
+    eprintln!("    {} lookup <data-directory | eDNE.zip> <cep>", program);
     eprintln!();
     eprintln!("Types:");
     eprintln!("  locality      Parse LOG_LOCALIDADE.TXT file");
@@ -62,7 +69,12 @@ fn print_usage(program: &str) {
     eprintln!();
     eprintln!("Examples:");
     eprintln!("  {} locality LOG_LOCALIDADE.TXT", program);
+    eprintln!(
+        "  unzip -p eDNE.zip LOG_BAIRRO.TXT | {} neighborhood -",
+        program
+    );
     eprintln!("  {} build-index data", program);
+    eprintln!("  {} build-index eDNE_Basico.zip", program);
     eprintln!("  {} lookup data 69918703", program);
 }
 
@@ -142,23 +154,27 @@ fn parse_command(args: &[String]) -> Command {
 }
 
 fn parse_file(file_type: FileType, file_path: &str) {
-    println!("Reading file: {}", file_path);
-
-    let bytes = match fs::read(file_path) {
-        Ok(data) => data,
-        Err(e) => {
-            eprintln!("Error reading file '{}': {}", file_path, e);
-            process::exit(1);
+    let reader: Box<dyn Read> = if file_path == "-" {
+        println!("Reading from stdin");
+        Box::new(io::stdin().lock())
+    } else {
+        println!("Reading file: {}", file_path);
+        match File::open(file_path) {
+            Ok(file) => Box::new(file),
+            Err(e) => {
+                eprintln!("Error reading file '{}': {}", file_path, e);
+                process::exit(1);
+            }
         }
     };
 
     match file_type {
-        FileType::Locality => parse_localities(&bytes),
-        FileType::Neighborhood => parse_neighborhoods(&bytes),
-        FileType::Cpc => parse_cpcs(&bytes),
-        FileType::BigUser => parse_big_users(&bytes),
-        FileType::OperationalUnit => parse_operational_units(&bytes),
-        FileType::Address => parse_addresses(&bytes),
+        FileType::Locality => parse_localities(reader),
+        FileType::Neighborhood => parse_neighborhoods(reader),
+        FileType::Cpc => parse_cpcs(reader),
+        FileType::BigUser => parse_big_users(reader),
+        FileType::OperationalUnit => parse_operational_units(reader),
+        FileType::Address => parse_addresses(reader),
     }
 }
 
@@ -169,7 +185,7 @@ fn build_index(data_dir: &str) {
     let lookup = match build_cep_lookup(data_dir) {
         Ok(lookup) => lookup,
         Err(e) => {
-            eprintln!("Error building index: {}", e);
+            eprintln!("Error building index: {:#}", e);
             process::exit(1);
         }
     };
@@ -204,13 +220,21 @@ fn build_index(data_dir: &str) {
 }
 
 fn lookup_cep(data_dir: &str, cep: &str) {
+    let cep: Cep = match cep.parse() {
+        Ok(cep) => cep,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
+    };
+
     println!("Loading data and building index...");
     println!();
 
     let lookup = match build_cep_lookup(data_dir) {
         Ok(lookup) => lookup,
         Err(e) => {
-            eprintln!("Error building index: {}", e);
+            eprintln!("Error building index: {:#}", e);
             process::exit(1);
         }
     };
@@ -267,6 +291,10 @@ fn print_cep_info(info: &CepInfo) {
 fn build_cep_lookup(
     data_dir: &str,
 ) -> Result<cep_lookup::CepLookup, Box<dyn std::error::Error>> {
+    if Path::new(data_dir).is_file() {
+        return build_cep_lookup_from_archive(Path::new(data_dir));
+    }
+
     let mut builder = CepLookupBuilder::new();
 
     println!("Loading eDNE data...");
@@ -274,8 +302,7 @@ fn build_cep_lookup(
     // Load LOG_LOCALIDADE
     let loc_path = format!("{}/log/LOG_LOCALIDADE.TXT", data_dir);
     if Path::new(&loc_path).exists() {
-        let bytes = fs::read(&loc_path)?;
-        let localities = Localities::from_iso8859_1(&bytes)?;
+        let localities = Localities::from_path(Path::new(&loc_path))?;
         println!("✓ {} localities", localities.len());
         builder.add_localities(localities);
     }
@@ -283,8 +310,8 @@ fn build_cep_lookup(
     // Load LOG_BAIRRO
     let neighborhood_path = format!("{}/log/LOG_BAIRRO.TXT", data_dir);
     if Path::new(&neighborhood_path).exists() {
-        let bytes = fs::read(&neighborhood_path)?;
-        let neighborhoods = Neighborhoods::from_iso8859_1(&bytes)?;
+        let neighborhoods =
+            Neighborhoods::from_path(Path::new(&neighborhood_path))?;
         println!("✓ {} neighborhoods", neighborhoods.len());
         builder.add_neighborhoods(neighborhoods);
     }
@@ -293,8 +320,7 @@ fn build_cep_lookup(
     for uf in edne::models::Uf::iter() {
         let log_path = format!("{}/log/LOG_LOGRADOURO_{}.TXT", data_dir, uf);
         if Path::new(&log_path).exists() {
-            let bytes = fs::read(&log_path)?;
-            let addresses = Addresses::from_iso8859_1(&bytes)?;
+            let addresses = Addresses::from_path(Path::new(&log_path))?;
             println!("✓ {} addresses ({})", addresses.len(), uf);
             builder.add_addresses(addresses);
         }
@@ -303,8 +329,7 @@ fn build_cep_lookup(
     // Load LOG_GRANDE_USUARIO
     let gu_path = format!("{}/log/LOG_GRANDE_USUARIO.TXT", data_dir);
     if Path::new(&gu_path).exists() {
-        let bytes = fs::read(&gu_path)?;
-        let big_users = BigUsers::from_iso8859_1(&bytes)?;
+        let big_users = BigUsers::from_path(Path::new(&gu_path))?;
         println!("✓ {} big users", big_users.len());
         builder.add_big_users(big_users);
     }
@@ -312,8 +337,7 @@ fn build_cep_lookup(
     // Load LOG_UNID_OPER
     let uo_path = format!("{}/log/LOG_UNID_OPER.TXT", data_dir);
     if Path::new(&uo_path).exists() {
-        let bytes = fs::read(&uo_path)?;
-        let units = OperationalUnits::from_iso8859_1(&bytes)?;
+        let units = OperationalUnits::from_path(Path::new(&uo_path))?;
         println!("✓ {} operational units", units.len());
         builder.add_operational_units(units);
     }
@@ -321,8 +345,7 @@ fn build_cep_lookup(
     // Load LOG_CPC
     let cpc_path = format!("{}/log/LOG_CPC.TXT", data_dir);
     if Path::new(&cpc_path).exists() {
-        let bytes = fs::read(&cpc_path)?;
-        let cpcs = Cpcs::from_iso8859_1(&bytes)?;
+        let cpcs = Cpcs::from_path(Path::new(&cpc_path))?;
         println!("✓ {} CPCs", cpcs.len());
         builder.add_cpcs(cpcs);
     }
@@ -332,13 +355,50 @@ fn build_cep_lookup(
     Ok(builder.build())
 }
 
-fn parse_localities(bytes: &[u8]) {
+fn build_cep_lookup_from_archive(
+    path: &Path,
+) -> Result<cep_lookup::CepLookup, Box<dyn std::error::Error>> {
+    let mut builder = CepLookupBuilder::new();
+
+    println!("Loading eDNE data from archive...");
+    let archive = Archive::open(path)?;
+
+    let localities = Localities::from_archive(&archive)?;
+    println!("✓ {} localities", localities.len());
+    builder.add_localities(localities);
+
+    let neighborhoods = Neighborhoods::from_archive(&archive)?;
+    println!("✓ {} neighborhoods", neighborhoods.len());
+    builder.add_neighborhoods(neighborhoods);
+
+    let addresses = Addresses::from_archive(&archive)?;
+    println!("✓ {} addresses", addresses.len());
+    builder.add_addresses(addresses);
+
+    let big_users = BigUsers::from_archive(&archive)?;
+    println!("✓ {} big users", big_users.len());
+    builder.add_big_users(big_users);
+
+    let units = OperationalUnits::from_archive(&archive)?;
+    println!("✓ {} operational units", units.len());
+    builder.add_operational_units(units);
+
+    let cpcs = Cpcs::from_archive(&archive)?;
+    println!("✓ {} CPCs", cpcs.len());
+    builder.add_cpcs(cpcs);
+
+    println!();
+    println!("Building CEP index...");
+    Ok(builder.build())
+}
+
+fn parse_localities(reader: impl Read) {
     println!("Parsing localities...");
 
-    let localities = match Localities::from_iso8859_1(bytes) {
+    let localities = match Localities::from_reader(reader) {
         Ok(data) => data,
         Err(e) => {
-            eprintln!("Error parsing file: {}", e);
+            eprintln!("Error parsing file: {:#}", e);
             process::exit(1);
         }
     };
@@ -456,13 +516,13 @@ fn parse_localities(bytes: &[u8]) {
     println!();
 }
 
-fn parse_neighborhoods(bytes: &[u8]) {
+fn parse_neighborhoods(reader: impl Read) {
     println!("Parsing neighborhoods...");
 
-    let neighborhoods = match Neighborhoods::from_iso8859_1(bytes) {
+    let neighborhoods = match Neighborhoods::from_reader(reader) {
         Ok(data) => data,
         Err(e) => {
-            eprintln!("Error parsing file: {}", e);
+            eprintln!("Error parsing file: {:#}", e);
             process::exit(1);
         }
     };
@@ -558,13 +618,13 @@ fn parse_neighborhoods(bytes: &[u8]) {
     println!();
 }
 
-fn parse_cpcs(bytes: &[u8]) {
+fn parse_cpcs(reader: impl Read) {
     println!("Parsing CPCs (Community Postal Boxes)...");
 
-    let cpcs = match Cpcs::from_iso8859_1(bytes) {
+    let cpcs = match Cpcs::from_reader(reader) {
         Ok(data) => data,
         Err(e) => {
-            eprintln!("Error parsing file: {}", e);
+            eprintln!("Error parsing file: {:#}", e);
             process::exit(1);
         }
     };
@@ -641,13 +701,13 @@ fn parse_cpcs(bytes: &[u8]) {
     println!();
 }
 
-fn parse_big_users(bytes: &[u8]) {
+fn parse_big_users(reader: impl Read) {
     println!("Parsing big users...");
 
-    let big_users = match BigUsers::from_iso8859_1(bytes) {
+    let big_users = match BigUsers::from_reader(reader) {
         Ok(data) => data,
         Err(e) => {
-            eprintln!("Error parsing file: {}", e);
+            eprintln!("Error parsing file: {:#}", e);
             process::exit(1);
         }
     };
@@ -743,13 +803,13 @@ fn parse_big_users(bytes: &[u8]) {
     println!();
 }
 
-fn parse_operational_units(bytes: &[u8]) {
+fn parse_operational_units(reader: impl Read) {
     println!("Parsing operational units...");
 
-    let units = match OperationalUnits::from_iso8859_1(bytes) {
+    let units = match OperationalUnits::from_reader(reader) {
         Ok(data) => data,
         Err(e) => {
-            eprintln!("Error parsing file: {}", e);
+            eprintln!("Error parsing file: {:#}", e);
             process::exit(1);
         }
     };
@@ -842,13 +902,13 @@ fn parse_operational_units(bytes: &[u8]) {
     println!();
 }
 
-fn parse_addresses(bytes: &[u8]) {
+fn parse_addresses(reader: impl Read) {
     println!("Parsing addresses (streets)...");
 
-    let addresses = match Addresses::from_iso8859_1(bytes) {
+    let addresses = match Addresses::from_reader(reader) {
         Ok(data) => data,
         Err(e) => {
-            eprintln!("Error parsing file: {}", e);
+            eprintln!("Error parsing file: {:#}", e);
             process::exit(1);
         }
     };
@@ -19,7 +19,7 @@ use criterion::{
 };
 use std::hint::black_box;
 
-use edne::parser::localities::Localities;
+use edne::parser::{Collection, localities::Localities};
 
 const SAMPLE_DATA: &str = "\
 15321@AC@Terra Indgena Mamoadate@69939810@0@P@2@Terra Ind Mamoadate@
@@ -15,7 +15,7 @@
 //
 
 use edne::models::AddressId;
-use edne::parser::addresses::Addresses;
+use edne::parser::{Collection, addresses::Addresses};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let sample_data = "\
@@ -15,7 +15,7 @@
 //
 
 use edne::models::BigUserId;
-use edne::parser::big_users::BigUsers;
+use edne::parser::{Collection, big_users::BigUsers};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let sample_data = "\
@@ -15,7 +15,7 @@
 //
 
 use edne::models::{CpcId, LocalityId};
-use edne::parser::cpcs::Cpcs;
+use edne::parser::{Collection, cpcs::Cpcs};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Sample data (in real usage, read from file)
@@ -132,7 +132,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("--- Example 7: CEP Analysis ---");
     let mut cep_prefixes = std::collections::HashMap::new();
     for (_, cpc) in cpcs.iter() {
-        let prefix = &cpc.cep[0..5];
+        let prefix = cpc.cep.prefix(5);
         *cep_prefixes.entry(prefix).or_insert(0) += 1;
     }
 
@@ -142,7 +142,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     println!("Top 5 CEP prefixes:");
     for (prefix, count) in prefixes.iter().take(5) {
-        println!("  {}: {} CPCs", prefix, count);
+        println!("  {:05}: {} CPCs", prefix, count);
     }
     println!();
 
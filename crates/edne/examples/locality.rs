@@ -15,7 +15,7 @@
 //
 
 use edne::models::{LocalityId, LocalitySituation, LocalityType, Uf};
-use edne::parser::localities::Localities;
+use edne::parser::{Collection, localities::Localities};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Sample data (in real usage, read from file)
@@ -15,7 +15,7 @@
 //
 
 use edne::models::{LocalityId, NeighborhoodId};
-use edne::parser::neighborhoods::Neighborhoods;
+use edne::parser::{Collection, neighborhoods::Neighborhoods};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Sample data (in real usage, read from file)
@@ -15,7 +15,7 @@
 //
 
 use edne::models::{OperationalUnitId, PostBoxIndicator};
-use edne::parser::operational_units::OperationalUnits;
+use edne::parser::{Collection, operational_units::OperationalUnits};
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let sample_data = "\
@@ -16,15 +16,122 @@
 
 //! Error types used throughout the eDNE crate.
 
+use std::{error::Error, fmt};
+
 pub use crate::models::address::{AddressIdError, StreetTypeIndicatorError};
 pub use crate::models::big_user::{BigUserIdError, StreetIdError};
+pub use crate::models::cep::CepError;
 pub use crate::models::cpc::CpcIdError;
+pub use crate::models::delta::DeltaOperationError;
+pub use crate::models::ibge::IbgeMunicipalityCodeError;
 pub use crate::models::locality::{
     LocalityIdError, LocalitySituationError, LocalityTypeError,
 };
+pub use crate::models::locality_cep_range::LocalityRangeTypeError;
 pub use crate::models::neighborhood::NeighborhoodIdError;
+pub use crate::models::number_section::SectionSideError;
 pub use crate::models::operational_unit::{
     OperationalUnitIdError, PostBoxIndicatorError,
 };
+pub use crate::models::region::RegionParseError;
 pub use crate::models::uf::UfParseError;
-pub use crate::parser::base::ParseError;
+pub use crate::parser::{
+    base::{ErrorKind, ParseError},
+    span::SourceSpan,
+};
+
+/// Typed error of a field value rejected by a model type.
+///
+/// Carried by `ParseError::InvalidValue`, whose [`Error::source`] returns
+/// the wrapped model error so it can be downcast to its concrete type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValueError {
+    /// Invalid address ID (LOG_NU).
+    AddressId(AddressIdError),
+    /// Invalid street type indicator (LOG_STA_TLO).
+    StreetTypeIndicator(StreetTypeIndicatorError),
+    /// Invalid big user ID (GRU_NU).
+    BigUserId(BigUserIdError),
+    /// Invalid street ID of a big user or operational unit.
+    StreetId(StreetIdError),
+    /// Invalid postal code (CEP).
+    Cep(CepError),
+    /// Invalid CPC ID (CPC_NU).
+    CpcId(CpcIdError),
+    /// Invalid delta operation code.
+    DeltaOperation(DeltaOperationError),
+    /// Invalid IBGE municipality code (MUN_NU).
+    IbgeMunicipalityCode(IbgeMunicipalityCodeError),
+    /// Invalid locality ID.
+    LocalityId(LocalityIdError),
+    /// Invalid locality situation (LOC_IN_SIT).
+    LocalitySituation(LocalitySituationError),
+    /// Invalid locality type (LOC_IN_TIPO_LOC).
+    LocalityType(LocalityTypeError),
+    /// Invalid locality range type (LOC_TIPO_FAIXA).
+    LocalityRangeType(LocalityRangeTypeError),
+    /// Invalid neighborhood ID.
+    NeighborhoodId(NeighborhoodIdError),
+    /// Invalid street side (SEC_IN_LADO).
+    SectionSide(SectionSideError),
+    /// Invalid operational unit ID (UOP_NU).
+    OperationalUnitId(OperationalUnitIdError),
+    /// Invalid post box indicator (UOP_IN_CP).
+    PostBoxIndicator(PostBoxIndicatorError),
+    /// Invalid federative unit (UFE_SG).
+    Uf(UfParseError),
+}
+
+macro_rules! value_errors {
+    ($($variant:ident($error:ty)),* $(,)?) => {
+        impl ValueError {
+            /// Returns the wrapped model error.
+            pub fn inner(&self) -> &(dyn Error + Send + Sync + 'static) {
+                match self {
+                    $(Self::$variant(e) => e,)*
+                }
+            }
+        }
+
+        $(
+            impl From<$error> for ValueError {
+                fn from(e: $error) -> Self {
+                    Self::$variant(e)
+                }
+            }
+        )*
+    };
+}
+
+value_errors! {
+    AddressId(AddressIdError),
+    StreetTypeIndicator(StreetTypeIndicatorError),
+    BigUserId(BigUserIdError),
+    StreetId(StreetIdError),
+    Cep(CepError),
+    CpcId(CpcIdError),
+    DeltaOperation(DeltaOperationError),
+    IbgeMunicipalityCode(IbgeMunicipalityCodeError),
+    LocalityId(LocalityIdError),
+    LocalitySituation(LocalitySituationError),
+    LocalityType(LocalityTypeError),
+    LocalityRangeType(LocalityRangeTypeError),
+    NeighborhoodId(NeighborhoodIdError),
+    SectionSide(SectionSideError),
+    OperationalUnitId(OperationalUnitIdError),
+    PostBoxIndicator(PostBoxIndicatorError),
+    Uf(UfParseError),
+}
+
+impl fmt::Display for ValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.inner(), f)
+    }
+}
+
+impl Error for ValueError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.inner().source()
+    }
+}
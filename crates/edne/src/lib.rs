@@ -24,9 +24,13 @@
 //! ## Features
 //!
 //! - Zero-copy parsing where possible
-//! - Automatic ISO-8859-1 to UTF-8 conversion
+//! - ISO-8859-1, Windows-1252 and UTF-8 input with encoding detection
 //! - Type-safe models with validation
 //! - Efficient HashMap-based collections
+//! - Delimited ('@') and fixed-width column layouts
+//! - Reading files straight from the eDNE zip archive
+//! - Async parsing from tokio readers (`async` feature)
+//! - Serde support for models and collections (`serde` feature)
 //! - Comprehensive error handling
 //!
 //! ## Example
@@ -47,10 +51,23 @@
 pub mod error;
 pub mod models;
 pub mod parser;
+pub mod prelude;
+#[cfg(feature = "serde")]
+mod serde_impls;
 
-pub use error::ParseError;
+pub use error::{ErrorKind, ParseError, SourceSpan, ValueError};
 pub use models::{
-    Address, AddressId, BigUser, BigUserId, Cpc, CpcId, Locality, LocalityId,
-    Neighborhood, NeighborhoodId, OperationalUnit, OperationalUnitId,
-    PostBoxIndicator, StreetId, StreetTypeIndicator, Uf,
+    Address, AddressId, AddressNameVariant, AddressRef, BigUser, BigUserId,
+    BigUserRef, Cep, Country, Cpc, CpcId, CpcPostBoxRange, CpcRef, Delta,
+    DeltaOperation, IbgeMunicipalityCode, Locality, LocalityCepRange,
+    LocalityId, LocalityNameVariant, LocalityRangeType, LocalityRef,
+    Neighborhood, NeighborhoodCepRange, NeighborhoodId,
+    NeighborhoodNameVariant, NeighborhoodRef, NumberSection, OperationalUnit,
+    OperationalUnitId, OperationalUnitPostBoxRange, OperationalUnitRef,
+    PostBoxIndicator, Region, SectionSide, StreetId, StreetType,
+    StreetTypeIndicator, Uf, UfCepRange,
+};
+pub use parser::{
+    collection::{Collection, DeltaCollection},
+    encoding::Encoding,
 };
@@ -16,7 +16,7 @@
 
 use std::{error::Error, fmt, str::FromStr};
 
-use crate::models::{LocalityId, NeighborhoodId, Uf};
+use crate::models::{Cep, LocalityId, NeighborhoodId, StreetType, Uf};
 
 /// Unique identifier for an address (street/logradouro).
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -138,6 +138,7 @@ impl Error for StreetTypeIndicatorError {}
 /// localities in coding phase (LOC_IN_SIT=3). To find the neighborhood
 /// of the street, use BAI_NU_INI (relates to LOG_BAIRRO, field BAI_NU).
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Address {
     /// Unique identifier for the address (LOG_NU).
     pub id: AddressId,
@@ -154,15 +155,68 @@ pub struct Address {
     /// Complement (LOG_COMPLEMENTO) - optional.
     pub complement: Option<String>,
     /// Postal code (CEP).
-    pub cep: String,
+    pub cep: Cep,
     /// Street type (TLO_TX) - e.g., "Rua", "Avenida", "Travessa".
-    pub street_type: String,
+    pub street_type: StreetType,
     /// Indicator to use street type (LOG_STA_TLO) - optional.
     pub street_type_indicator: Option<StreetTypeIndicator>,
     /// Abbreviated name (LOG_NO_ABREV) - optional.
     pub abbreviated_name: Option<String>,
 }
 
+/// Borrowed variant of [`Address`] whose text fields are slices of the
+/// decoded line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AddressRef<'a> {
+    /// Unique identifier for the address (LOG_NU).
+    pub id: AddressId,
+    /// Federative unit abbreviation (UFE_SG).
+    pub uf: Uf,
+    /// Locality ID (LOC_NU).
+    pub locality_id: LocalityId,
+    /// Initial neighborhood ID of the street (BAI_NU_INI).
+    pub neighborhood_id_start: NeighborhoodId,
+    /// Final neighborhood ID of the street (BAI_NU_FIM) - optional.
+    pub neighborhood_id_end: Option<NeighborhoodId>,
+    /// Name of the street (LOG_NO).
+    pub name: &'a str,
+    /// Complement (LOG_COMPLEMENTO) - optional.
+    pub complement: Option<&'a str>,
+    /// Postal code (CEP).
+    pub cep: Cep,
+    /// Street type (TLO_TX) - e.g., "Rua", "Avenida", "Travessa".
+    pub street_type: &'a str,
+    /// Indicator to use street type (LOG_STA_TLO) - optional.
+    pub street_type_indicator: Option<StreetTypeIndicator>,
+    /// Abbreviated name (LOG_NO_ABREV) - optional.
+    pub abbreviated_name: Option<&'a str>,
+}
+
+impl AddressRef<'_> {
+    /// Converts into an owned [`Address`], allocating its text fields.
+    pub fn to_owned(&self) -> Address {
+        Address {
+            id: self.id,
+            uf: self.uf,
+            locality_id: self.locality_id,
+            neighborhood_id_start: self.neighborhood_id_start,
+            neighborhood_id_end: self.neighborhood_id_end,
+            name: self.name.to_string(),
+            complement: self.complement.map(str::to_string),
+            cep: self.cep,
+            street_type: StreetType::from(self.street_type),
+            street_type_indicator: self.street_type_indicator,
+            abbreviated_name: self.abbreviated_name.map(str::to_string),
+        }
+    }
+}
+
+impl From<AddressRef<'_>> for Address {
+    fn from(record: AddressRef<'_>) -> Self {
+        record.to_owned()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -16,7 +16,7 @@
 
 use std::{error::Error, fmt, str::FromStr};
 
-use crate::models::{LocalityId, NeighborhoodId, Uf};
+use crate::models::{Cep, LocalityId, NeighborhoodId, Uf};
 
 /// Unique identifier for a big user.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -154,6 +154,7 @@ impl Error for StreetIdError {}
 /// banks, public agencies, etc). For non-coded localities (LOC_IN_SIT=0),
 /// the LOG_NU field is empty and GRU_ENDERECO should be used for addressing.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BigUser {
     /// Unique identifier for the big user (GRU_NU).
     pub id: BigUserId,
@@ -170,11 +171,58 @@ pub struct BigUser {
     /// Address of the big user (GRU_ENDERECO).
     pub address: String,
     /// Postal code (CEP).
-    pub cep: String,
+    pub cep: Cep,
     /// Abbreviated name (GRU_NO_ABREV) - optional.
     pub abbreviated_name: Option<String>,
 }
 
+/// Borrowed variant of [`BigUser`] whose text fields are slices of the
+/// decoded line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BigUserRef<'a> {
+    /// Unique identifier for the big user (GRU_NU).
+    pub id: BigUserId,
+    /// Federative unit abbreviation (UFE_SG).
+    pub uf: Uf,
+    /// Locality ID (LOC_NU).
+    pub locality_id: LocalityId,
+    /// Neighborhood ID (BAI_NU).
+    pub neighborhood_id: NeighborhoodId,
+    /// Street ID (LOG_NU) - optional, empty for non-coded localities.
+    pub street_id: Option<StreetId>,
+    /// Name of the big user (GRU_NO).
+    pub name: &'a str,
+    /// Address of the big user (GRU_ENDERECO).
+    pub address: &'a str,
+    /// Postal code (CEP).
+    pub cep: Cep,
+    /// Abbreviated name (GRU_NO_ABREV) - optional.
+    pub abbreviated_name: Option<&'a str>,
+}
+
+impl BigUserRef<'_> {
+    /// Converts into an owned [`BigUser`], allocating its text fields.
+    pub fn to_owned(&self) -> BigUser {
+        BigUser {
+            id: self.id,
+            uf: self.uf,
+            locality_id: self.locality_id,
+            neighborhood_id: self.neighborhood_id,
+            street_id: self.street_id,
+            name: self.name.to_string(),
+            address: self.address.to_string(),
+            cep: self.cep,
+            abbreviated_name: self.abbreviated_name.map(str::to_string),
+        }
+    }
+}
+
+impl From<BigUserRef<'_>> for BigUser {
+    fn from(record: BigUserRef<'_>) -> Self {
+        record.to_owned()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
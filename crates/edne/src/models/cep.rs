@@ -0,0 +1,211 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+use std::{error::Error, fmt, str::FromStr};
+
+/// Brazilian postal code (Código de Endereçamento Postal).
+///
+/// Stored as a number, so leading zeros are restored on display. The
+/// digits are hierarchical: region, sub-region, sector, subsector,
+/// subsector divider and a three digit suffix.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Cep(u32);
+
+impl Cep {
+    /// Number of digits in a CEP.
+    pub const DIGITS: usize = 8;
+
+    /// Largest value a CEP can hold.
+    pub const MAX: u32 = 99_999_999;
+
+    /// Creates a new `Cep` from its numeric value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` has more than eight digits.
+    pub const fn new(value: u32) -> Self {
+        assert!(value <= Self::MAX, "CEP must have at most eight digits");
+        Self(value)
+    }
+
+    /// Returns the inner value.
+    pub const fn get(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns the first `n` digits of the CEP as a number.
+    ///
+    /// `prefix(1)` is the region, `prefix(2)` the sub-region and
+    /// `prefix(3)` the sector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than eight.
+    pub const fn prefix(&self, n: usize) -> u32 {
+        assert!(
+            n <= Self::DIGITS,
+            "CEP prefix must have at most eight digits"
+        );
+        self.0 / 10u32.pow((Self::DIGITS - n) as u32)
+    }
+}
+
+impl TryFrom<u32> for Cep {
+    type Error = CepError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        if value > Self::MAX {
+            return Err(CepError::InvalidFormat(value.to_string()));
+        }
+        Ok(Self(value))
+    }
+}
+
+impl FromStr for Cep {
+    type Err = CepError;
+
+    /// Parses `00000000`, `00000-000` or `00000 000`, ignoring
+    /// surrounding whitespace.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            return Err(CepError::Empty);
+        }
+        let invalid = || CepError::InvalidFormat(s.to_string());
+
+        let (head, tail) = match trimmed.split_at_checked(5) {
+            Some((head, rest))
+                if !rest.starts_with(|c: char| c.is_ascii_digit()) =>
+            {
+                let tail = rest
+                    .strip_prefix('-')
+                    .unwrap_or_else(|| rest.trim_start());
+                if tail.len() == rest.len() {
+                    return Err(invalid());
+                }
+                (head, tail)
+            }
+            _ => (trimmed, ""),
+        };
+        let digits = head.len() + tail.len();
+        if digits != Self::DIGITS
+            || !head.bytes().chain(tail.bytes()).all(|b| b.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+
+        let value = head
+            .bytes()
+            .chain(tail.bytes())
+            .fold(0, |acc, b| acc * 10 + u32::from(b - b'0'));
+        Ok(Self(value))
+    }
+}
+
+impl fmt::Display for Cep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:05}-{:03}", self.0 / 1000, self.0 % 1000)
+    }
+}
+
+/// Errors when parsing or creating a `Cep`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CepError {
+    /// Empty input after trimming.
+    Empty,
+    /// Input is not eight digits, optionally split by `-` or spaces.
+    InvalidFormat(String),
+}
+
+impl fmt::Display for CepError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "CEP is empty"),
+            Self::InvalidFormat(s) => {
+                write!(f, "invalid CEP format: '{}'", s)
+            }
+        }
+    }
+}
+
+impl Error for CepError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cep_from_str_accepted_formats() {
+        let expected = Cep::new(1310100);
+        assert_eq!(Cep::from_str("01310-100").unwrap(), expected);
+        assert_eq!(Cep::from_str("01310100").unwrap(), expected);
+        assert_eq!(Cep::from_str(" 01310 100 ").unwrap(), expected);
+    }
+
+    #[test]
+    fn cep_from_str_empty() {
+        assert_eq!(Cep::from_str("  "), Err(CepError::Empty));
+    }
+
+    #[test]
+    fn cep_from_str_invalid() {
+        for input in [
+            "0131010",
+            "013101000",
+            "0131-0100",
+            "01310-10a",
+            "01310--100",
+            "01310_100",
+            "+1310100",
+            "013 10100",
+        ] {
+            assert_eq!(
+                Cep::from_str(input),
+                Err(CepError::InvalidFormat(input.to_string())),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn cep_display_canonical() {
+        assert_eq!(Cep::new(1310100).to_string(), "01310-100");
+        assert_eq!(Cep::new(69918703).to_string(), "69918-703");
+        assert_eq!(Cep::new(0).to_string(), "00000-000");
+    }
+
+    #[test]
+    fn cep_prefix() {
+        let cep = Cep::new(69918703);
+        assert_eq!(cep.prefix(0), 0);
+        assert_eq!(cep.prefix(1), 6);
+        assert_eq!(cep.prefix(2), 69);
+        assert_eq!(cep.prefix(3), 699);
+        assert_eq!(cep.prefix(5), 69918);
+        assert_eq!(cep.prefix(8), 69918703);
+    }
+
+    #[test]
+    fn cep_try_from_u32() {
+        assert_eq!(Cep::try_from(69918703).unwrap().get(), 69918703);
+        assert!(Cep::try_from(100_000_000).is_err());
+    }
+
+    #[test]
+    fn cep_ordering_follows_value() {
+        assert!(Cep::new(1310100) < Cep::new(69918703));
+    }
+}
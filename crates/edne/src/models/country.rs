@@ -0,0 +1,36 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+/// Represents a country from the ECT country table (ECT_PAIS).
+///
+/// Unlike the other eDNE tables, ECT_PAIS is not Brazil-specific: it lists
+/// the country codes and names used by Correios for international mail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Country {
+    /// Two-letter country code (PAI_SG) - e.g., "BR".
+    pub code: String,
+    /// Alternative country code (PAI_SG_ALTERNATIVA) - optional.
+    pub alternative_code: Option<String>,
+    /// Country name in Portuguese (PAI_NO_PORTUGUES).
+    pub name_portuguese: String,
+    /// Country name in English (PAI_NO_INGLES) - optional.
+    pub name_english: Option<String>,
+    /// Country name in French (PAI_NO_FRANCES) - optional.
+    pub name_french: Option<String>,
+    /// Abbreviated country name (PAI_ABREVIATURA) - optional.
+    pub abbreviation: Option<String>,
+}
@@ -16,7 +16,7 @@
 
 use std::{error::Error, fmt, str::FromStr};
 
-use crate::models::{LocalityId, Uf};
+use crate::models::{Cep, LocalityId, Uf};
 
 /// Unique identifier for a community postal box (CPC).
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -93,6 +93,7 @@ impl Error for CpcIdError {}
 ///
 /// CPCs serve rural and peripheral urban areas not covered by home delivery.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Cpc {
     /// Unique identifier for the CPC (CPC_NU).
     pub id: CpcId,
@@ -105,7 +106,45 @@ pub struct Cpc {
     /// Address of the CPC (CPC_ENDERECO).
     pub address: String,
     /// Postal code (CEP).
-    pub cep: String,
+    pub cep: Cep,
+}
+
+/// Borrowed variant of [`Cpc`] whose text fields are slices of the
+/// decoded line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CpcRef<'a> {
+    /// Unique identifier for the CPC (CPC_NU).
+    pub id: CpcId,
+    /// Federative unit abbreviation (UFE_SG).
+    pub uf: Uf,
+    /// Locality ID this CPC belongs to (LOC_NU).
+    pub locality_id: LocalityId,
+    /// Name of the CPC (CPC_NO).
+    pub name: &'a str,
+    /// Address of the CPC (CPC_ENDERECO).
+    pub address: &'a str,
+    /// Postal code (CEP).
+    pub cep: Cep,
+}
+
+impl CpcRef<'_> {
+    /// Converts into an owned [`Cpc`], allocating its text fields.
+    pub fn to_owned(&self) -> Cpc {
+        Cpc {
+            id: self.id,
+            uf: self.uf,
+            locality_id: self.locality_id,
+            name: self.name.to_string(),
+            address: self.address.to_string(),
+            cep: self.cep,
+        }
+    }
+}
+
+impl From<CpcRef<'_>> for Cpc {
+    fn from(record: CpcRef<'_>) -> Self {
+        record.to_owned()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,123 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+use std::{error::Error, fmt, str::FromStr};
+
+/// Operation carried by a record of a DELTA_* update file.
+///
+/// - `Insert`: New record (INS)
+/// - `Update`: Existing record with changed fields (UPD)
+/// - `Delete`: Record removed from the base (DEL)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DeltaOperation {
+    /// Record inserted (INS).
+    Insert,
+    /// Record updated (UPD).
+    Update,
+    /// Record deleted (DEL).
+    Delete,
+}
+
+impl FromStr for DeltaOperation {
+    type Err = DeltaOperationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "INS" => Ok(Self::Insert),
+            "UPD" => Ok(Self::Update),
+            "DEL" => Ok(Self::Delete),
+            other => Err(DeltaOperationError::InvalidCode(other.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for DeltaOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let code = match self {
+            Self::Insert => "INS",
+            Self::Update => "UPD",
+            Self::Delete => "DEL",
+        };
+        write!(f, "{}", code)
+    }
+}
+
+/// Errors when parsing `DeltaOperation`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeltaOperationError {
+    /// Invalid operation code.
+    InvalidCode(String),
+}
+
+impl fmt::Display for DeltaOperationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCode(s) => {
+                write!(f, "invalid delta operation code: '{}'", s)
+            }
+        }
+    }
+}
+
+impl Error for DeltaOperationError {}
+
+/// A record from a DELTA_* monthly update file.
+///
+/// Delta files have the same columns as their base file plus a trailing
+/// operation code. Apply them to a parsed base with the `apply_delta`
+/// method of [`DeltaCollection`](crate::parser::DeltaCollection).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Delta<T> {
+    /// Operation to apply (trailing *_OPERACAO column).
+    pub operation: DeltaOperation,
+    /// Record as it appears in the delta file.
+    pub record: T,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delta_operation_from_str_valid() {
+        assert_eq!(
+            DeltaOperation::from_str("INS").unwrap(),
+            DeltaOperation::Insert
+        );
+        assert_eq!(
+            DeltaOperation::from_str("upd").unwrap(),
+            DeltaOperation::Update
+        );
+        assert_eq!(
+            DeltaOperation::from_str(" DEL ").unwrap(),
+            DeltaOperation::Delete
+        );
+    }
+
+    #[test]
+    fn delta_operation_from_str_invalid() {
+        let result = DeltaOperation::from_str("XYZ");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn delta_operation_display() {
+        assert_eq!(DeltaOperation::Insert.to_string(), "INS");
+        assert_eq!(DeltaOperation::Update.to_string(), "UPD");
+        assert_eq!(DeltaOperation::Delete.to_string(), "DEL");
+    }
+}
@@ -0,0 +1,201 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+use std::{error::Error, fmt, str::FromStr};
+
+use crate::models::Uf;
+
+/// Municipality codes published by IBGE whose check digit does not follow
+/// the algorithm. They are valid nonetheless.
+const CHECK_DIGIT_EXCEPTIONS: [u32; 9] = [
+    2201919, 2201988, 2202251, 2611533, 3117836, 3152131, 4305871, 5203939,
+    5203962,
+];
+
+/// Seven-digit IBGE municipality code (MUN_NU).
+///
+/// The first two digits are the IBGE state code and the last one is a
+/// check digit, both validated on construction.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IbgeMunicipalityCode(u32);
+
+impl IbgeMunicipalityCode {
+    /// Number of digits in a municipality code.
+    pub const DIGITS: usize = 7;
+
+    /// Returns the inner value.
+    pub const fn get(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns the state the municipality belongs to.
+    pub fn uf(&self) -> Uf {
+        // The state code is validated on construction.
+        Uf::from_ibge_code((self.0 / 100_000) as u8)
+            .expect("IBGE municipality code has a valid state")
+    }
+
+    /// Returns the check digit of `code`, the seventh digit of a valid
+    /// municipality code, computed from its first six digits.
+    fn check_digit(code: u32) -> u32 {
+        let sum: u32 = (0..6)
+            .map(|i| {
+                let digit = code / 10u32.pow(6 - i) % 10;
+                let product = digit * if i % 2 == 0 { 1 } else { 2 };
+                product / 10 + product % 10
+            })
+            .sum();
+        (10 - sum % 10) % 10
+    }
+}
+
+impl TryFrom<u32> for IbgeMunicipalityCode {
+    type Error = IbgeMunicipalityCodeError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        if !(1_000_000..=9_999_999).contains(&value) {
+            return Err(IbgeMunicipalityCodeError::InvalidFormat(
+                value.to_string(),
+            ));
+        }
+        if Uf::from_ibge_code((value / 100_000) as u8).is_err() {
+            return Err(IbgeMunicipalityCodeError::InvalidState(value));
+        }
+        if value % 10 != Self::check_digit(value)
+            && !CHECK_DIGIT_EXCEPTIONS.contains(&value)
+        {
+            return Err(IbgeMunicipalityCodeError::InvalidCheckDigit(value));
+        }
+        Ok(Self(value))
+    }
+}
+
+impl FromStr for IbgeMunicipalityCode {
+    type Err = IbgeMunicipalityCodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        if trimmed.len() != Self::DIGITS
+            || !trimmed.bytes().all(|b| b.is_ascii_digit())
+        {
+            return Err(IbgeMunicipalityCodeError::InvalidFormat(
+                s.to_string(),
+            ));
+        }
+        let value = trimmed.parse::<u32>().map_err(|_| {
+            IbgeMunicipalityCodeError::InvalidFormat(s.to_string())
+        })?;
+        Self::try_from(value)
+    }
+}
+
+impl fmt::Display for IbgeMunicipalityCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Errors when parsing or creating an `IbgeMunicipalityCode`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IbgeMunicipalityCodeError {
+    /// Input is not a seven-digit number.
+    InvalidFormat(String),
+    /// The first two digits are not an IBGE state code.
+    InvalidState(u32),
+    /// The last digit does not match the computed check digit.
+    InvalidCheckDigit(u32),
+}
+
+impl fmt::Display for IbgeMunicipalityCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFormat(s) => {
+                write!(f, "invalid IBGE municipality code format: '{}'", s)
+            }
+            Self::InvalidState(n) => {
+                write!(f, "IBGE municipality code {} has no valid state", n)
+            }
+            Self::InvalidCheckDigit(n) => {
+                write!(
+                    f,
+                    "IBGE municipality code {} has a wrong check digit",
+                    n
+                )
+            }
+        }
+    }
+}
+
+impl Error for IbgeMunicipalityCodeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ibge_code_from_str_valid() {
+        let code = IbgeMunicipalityCode::from_str("3550308").unwrap();
+        assert_eq!(code.get(), 3550308);
+        assert_eq!(code.uf(), Uf::SP);
+        assert_eq!(code.to_string(), "3550308");
+
+        let code = IbgeMunicipalityCode::from_str(" 1200401 ").unwrap();
+        assert_eq!(code.uf(), Uf::AC);
+    }
+
+    #[test]
+    fn ibge_code_invalid_format() {
+        for input in ["", "355030", "35503080", "355030a", "-355030"] {
+            assert_eq!(
+                IbgeMunicipalityCode::from_str(input),
+                Err(IbgeMunicipalityCodeError::InvalidFormat(
+                    input.to_string()
+                )),
+            );
+        }
+    }
+
+    #[test]
+    fn ibge_code_invalid_state() {
+        assert_eq!(
+            IbgeMunicipalityCode::from_str("3450308"),
+            Err(IbgeMunicipalityCodeError::InvalidState(3450308))
+        );
+    }
+
+    #[test]
+    fn ibge_code_invalid_check_digit() {
+        assert_eq!(
+            IbgeMunicipalityCode::from_str("3550307"),
+            Err(IbgeMunicipalityCodeError::InvalidCheckDigit(3550307))
+        );
+    }
+
+    #[test]
+    fn ibge_code_check_digit_exceptions() {
+        for code in CHECK_DIGIT_EXCEPTIONS {
+            assert_ne!(code % 10, IbgeMunicipalityCode::check_digit(code));
+            assert!(IbgeMunicipalityCode::try_from(code).is_ok());
+        }
+    }
+
+    #[test]
+    fn ibge_code_check_digit_known_codes() {
+        for code in [1200401, 3304557, 3550308, 5300108, 4314902] {
+            assert_eq!(code % 10, IbgeMunicipalityCode::check_digit(code));
+        }
+    }
+}
@@ -16,7 +16,7 @@
 
 use std::{error::Error, fmt, str::FromStr};
 
-use crate::models::Uf;
+use crate::models::{Cep, IbgeMunicipalityCode, Uf};
 
 /// Unique identifier for a locality.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -218,6 +218,7 @@ impl Error for LocalityTypeError {}
 /// A locality can be a municipality, district, or village with associated
 /// postal code information and geographic data.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Locality {
     /// Unique identifier for the locality (LOC_NU).
     pub id: LocalityId,
@@ -227,7 +228,7 @@ pub struct Locality {
     pub name: String,
     /// Postal code for non-coded localities (CEP).
     /// Only present when `situation` is `NotCoded`.
-    pub cep: Option<String>,
+    pub cep: Option<Cep>,
     /// Coding situation of the locality (LOC_IN_SIT).
     pub situation: LocalitySituation,
     /// Type of locality (LOC_IN_TIPO_LOC).
@@ -238,7 +239,56 @@ pub struct Locality {
     /// Abbreviated name of the locality (LOC_NO_ABREV).
     pub abbreviated_name: Option<String>,
     /// IBGE municipality code (MUN_NU).
-    pub ibge_code: Option<String>,
+    pub ibge_code: Option<IbgeMunicipalityCode>,
+}
+
+/// Borrowed variant of [`Locality`] whose text fields are slices of the
+/// decoded line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LocalityRef<'a> {
+    /// Unique identifier for the locality (LOC_NU).
+    pub id: LocalityId,
+    /// Federative unit abbreviation (UFE_SG).
+    pub uf: Uf,
+    /// Name of the locality (LOC_NO).
+    pub name: &'a str,
+    /// Postal code for non-coded localities (CEP).
+    /// Only present when `situation` is `NotCoded`.
+    pub cep: Option<Cep>,
+    /// Coding situation of the locality (LOC_IN_SIT).
+    pub situation: LocalitySituation,
+    /// Type of locality (LOC_IN_TIPO_LOC).
+    pub locality_type: LocalityType,
+    /// ID of the parent locality (LOC_NU_SUB).
+    /// Present when this locality is subordinate to another.
+    pub subordinate_to: Option<LocalityId>,
+    /// Abbreviated name of the locality (LOC_NO_ABREV).
+    pub abbreviated_name: Option<&'a str>,
+    /// IBGE municipality code (MUN_NU).
+    pub ibge_code: Option<IbgeMunicipalityCode>,
+}
+
+impl LocalityRef<'_> {
+    /// Converts into an owned [`Locality`], allocating its text fields.
+    pub fn to_owned(&self) -> Locality {
+        Locality {
+            id: self.id,
+            uf: self.uf,
+            name: self.name.to_string(),
+            cep: self.cep,
+            situation: self.situation,
+            locality_type: self.locality_type,
+            subordinate_to: self.subordinate_to,
+            abbreviated_name: self.abbreviated_name.map(str::to_string),
+            ibge_code: self.ibge_code,
+        }
+    }
+}
+
+impl From<LocalityRef<'_>> for Locality {
+    fn from(record: LocalityRef<'_>) -> Self {
+        record.to_owned()
+    }
 }
 
 #[cfg(test)]
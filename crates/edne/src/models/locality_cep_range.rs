@@ -0,0 +1,146 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+use std::{error::Error, fmt, str::FromStr};
+
+use crate::models::{Cep, LocalityId};
+
+/// Type of a locality CEP range.
+///
+/// - `Total`: Range covering the whole municipality (T)
+/// - `Exception`: Range exclusive to the urban seat of the municipality (C)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LocalityRangeType {
+    /// Whole municipality (LOC_TIPO_FAIXA = T).
+    Total,
+    /// Exclusive to the urban seat (LOC_TIPO_FAIXA = C).
+    Exception,
+}
+
+impl FromStr for LocalityRangeType {
+    type Err = LocalityRangeTypeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "T" => Ok(Self::Total),
+            "C" => Ok(Self::Exception),
+            other => {
+                Err(LocalityRangeTypeError::InvalidCode(other.to_string()))
+            }
+        }
+    }
+}
+
+impl fmt::Display for LocalityRangeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let code = match self {
+            Self::Total => "T",
+            Self::Exception => "C",
+        };
+        write!(f, "{}", code)
+    }
+}
+
+/// Errors when parsing `LocalityRangeType`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LocalityRangeTypeError {
+    /// Invalid range type code.
+    InvalidCode(String),
+}
+
+impl fmt::Display for LocalityRangeTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCode(s) => {
+                write!(f, "invalid locality range type code: '{}'", s)
+            }
+        }
+    }
+}
+
+impl Error for LocalityRangeTypeError {}
+
+/// Represents a CEP range of a locality from the eDNE database.
+///
+/// Coded localities span many CEPs, so LOG_FAIXA_LOCALIDADE lists the
+/// ranges that belong to each one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocalityCepRange {
+    /// Locality ID this range belongs to (LOC_NU).
+    pub locality_id: LocalityId,
+    /// First CEP of the range (LOC_CEP_INI).
+    pub cep_start: Cep,
+    /// Last CEP of the range (LOC_CEP_FIM).
+    pub cep_end: Cep,
+    /// Type of the range (LOC_TIPO_FAIXA).
+    pub range_type: LocalityRangeType,
+}
+
+impl LocalityCepRange {
+    /// Returns `true` if `cep` falls inside this range (inclusive).
+    pub fn contains(&self, cep: Cep) -> bool {
+        self.cep_start <= cep && cep <= self.cep_end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locality_range_type_from_str_valid() {
+        assert_eq!(
+            LocalityRangeType::from_str("T").unwrap(),
+            LocalityRangeType::Total
+        );
+        assert_eq!(
+            LocalityRangeType::from_str("C").unwrap(),
+            LocalityRangeType::Exception
+        );
+        assert_eq!(
+            LocalityRangeType::from_str("t").unwrap(),
+            LocalityRangeType::Total
+        );
+    }
+
+    #[test]
+    fn locality_range_type_from_str_invalid() {
+        let result = LocalityRangeType::from_str("X");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn locality_range_type_display() {
+        assert_eq!(LocalityRangeType::Total.to_string(), "T");
+        assert_eq!(LocalityRangeType::Exception.to_string(), "C");
+    }
+
+    #[test]
+    fn locality_cep_range_contains() {
+        let range = LocalityCepRange {
+            locality_id: LocalityId::new(16),
+            cep_start: Cep::new(69900001),
+            cep_end: Cep::new(69923999),
+            range_type: LocalityRangeType::Total,
+        };
+        assert!(range.contains(Cep::new(69900001)));
+        assert!(range.contains(Cep::new(69918703)));
+        assert!(range.contains(Cep::new(69923999)));
+        assert!(!range.contains(Cep::new(69900000)));
+        assert!(!range.contains(Cep::new(69924000)));
+    }
+}
@@ -15,24 +15,64 @@
 //
 
 pub mod locality;
-pub use locality::{Locality, LocalityId, LocalitySituation, LocalityType};
+pub use locality::{
+    Locality, LocalityId, LocalityRef, LocalitySituation, LocalityType,
+};
 
 pub mod uf;
 pub use uf::Uf;
 
+pub mod region;
+pub use region::Region;
+
+pub mod ibge;
+pub use ibge::IbgeMunicipalityCode;
+
+pub mod cep;
+pub use cep::Cep;
+
 pub mod neighborhood;
-pub use neighborhood::{Neighborhood, NeighborhoodId};
+pub use neighborhood::{Neighborhood, NeighborhoodId, NeighborhoodRef};
+
+pub mod neighborhood_cep_range;
+pub use neighborhood_cep_range::NeighborhoodCepRange;
+
+pub mod country;
+pub use country::Country;
 
 pub mod cpc;
-pub use cpc::{Cpc, CpcId};
+pub use cpc::{Cpc, CpcId, CpcRef};
+
+pub mod post_box_range;
+pub use post_box_range::{CpcPostBoxRange, OperationalUnitPostBoxRange};
+
+pub mod delta;
+pub use delta::{Delta, DeltaOperation};
 
 pub mod big_user;
-pub use big_user::{BigUser, BigUserId, StreetId};
+pub use big_user::{BigUser, BigUserId, BigUserRef, StreetId};
 
 pub mod operational_unit;
 pub use operational_unit::{
-    OperationalUnit, OperationalUnitId, PostBoxIndicator,
+    OperationalUnit, OperationalUnitId, OperationalUnitRef, PostBoxIndicator,
 };
 
 pub mod address;
-pub use address::{Address, AddressId, StreetTypeIndicator};
+pub use address::{Address, AddressId, AddressRef, StreetTypeIndicator};
+
+pub mod street_type;
+pub use street_type::StreetType;
+
+pub mod number_section;
+pub use number_section::{NumberSection, SectionSide};
+
+pub mod name_variant;
+pub use name_variant::{
+    AddressNameVariant, LocalityNameVariant, NeighborhoodNameVariant,
+};
+
+pub mod uf_cep_range;
+pub use uf_cep_range::UfCepRange;
+
+pub mod locality_cep_range;
+pub use locality_cep_range::{LocalityCepRange, LocalityRangeType};
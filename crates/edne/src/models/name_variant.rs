@@ -0,0 +1,56 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+use crate::models::{AddressId, LocalityId, NeighborhoodId, StreetType};
+
+/// Represents an alternate or historical name of a locality (LOG_VAR_LOC).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocalityNameVariant {
+    /// Locality ID this variant belongs to (LOC_NU).
+    pub locality_id: LocalityId,
+    /// Sequence number of the variant within the locality (VAL_NU).
+    pub sequence: u32,
+    /// Variant name of the locality (VAL_TX).
+    pub name: String,
+}
+
+/// Represents an alternate or historical name of a neighborhood
+/// (LOG_VAR_BAI).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NeighborhoodNameVariant {
+    /// Neighborhood ID this variant belongs to (BAI_NU).
+    pub neighborhood_id: NeighborhoodId,
+    /// Sequence number of the variant within the neighborhood (VDB_NU).
+    pub sequence: u32,
+    /// Variant name of the neighborhood (VDB_TX).
+    pub name: String,
+}
+
+/// Represents an alternate or historical name of a street (LOG_VAR_LOG).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddressNameVariant {
+    /// Address ID this variant belongs to (LOG_NU).
+    pub address_id: AddressId,
+    /// Sequence number of the variant within the street (VLO_NU).
+    pub sequence: u32,
+    /// Street type of the variant (TLO_TX) - e.g., "Rua", "Avenida".
+    pub street_type: StreetType,
+    /// Variant name of the street (VLO_TX).
+    pub name: String,
+}
@@ -94,6 +94,7 @@ impl Error for NeighborhoodIdError {}
 /// A neighborhood (bairro) is a subdivision within a locality,
 /// with optional abbreviated name.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Neighborhood {
     /// Unique identifier for the neighborhood (BAI_NU).
     pub id: NeighborhoodId,
@@ -107,6 +108,41 @@ pub struct Neighborhood {
     pub abbreviated_name: Option<String>,
 }
 
+/// Borrowed variant of [`Neighborhood`] whose text fields are slices of the
+/// decoded line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NeighborhoodRef<'a> {
+    /// Unique identifier for the neighborhood (BAI_NU).
+    pub id: NeighborhoodId,
+    /// Federative unit abbreviation (UFE_SG).
+    pub uf: Uf,
+    /// Locality ID this neighborhood belongs to (LOC_NU).
+    pub locality_id: LocalityId,
+    /// Name of the neighborhood (BAI_NO).
+    pub name: &'a str,
+    /// Abbreviated name of the neighborhood (BAI_NO_ABREV).
+    pub abbreviated_name: Option<&'a str>,
+}
+
+impl NeighborhoodRef<'_> {
+    /// Converts into an owned [`Neighborhood`], allocating its text fields.
+    pub fn to_owned(&self) -> Neighborhood {
+        Neighborhood {
+            id: self.id,
+            uf: self.uf,
+            locality_id: self.locality_id,
+            name: self.name.to_string(),
+            abbreviated_name: self.abbreviated_name.map(str::to_string),
+        }
+    }
+}
+
+impl From<NeighborhoodRef<'_>> for Neighborhood {
+    fn from(record: NeighborhoodRef<'_>) -> Self {
+        record.to_owned()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,58 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+use crate::models::{Cep, NeighborhoodId};
+
+/// Represents a CEP range of a neighborhood from the eDNE database.
+///
+/// A neighborhood may own more than one range, so LOG_FAIXA_BAIRRO can hold
+/// several records for the same neighborhood.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NeighborhoodCepRange {
+    /// Neighborhood ID this range belongs to (BAI_NU).
+    pub neighborhood_id: NeighborhoodId,
+    /// First CEP of the range (FCB_CEP_INI).
+    pub cep_start: Cep,
+    /// Last CEP of the range (FCB_CEP_FIM).
+    pub cep_end: Cep,
+}
+
+impl NeighborhoodCepRange {
+    /// Returns `true` if `cep` falls inside this range (inclusive).
+    pub fn contains(&self, cep: Cep) -> bool {
+        self.cep_start <= cep && cep <= self.cep_end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighborhood_cep_range_contains() {
+        let range = NeighborhoodCepRange {
+            neighborhood_id: NeighborhoodId::new(55400),
+            cep_start: Cep::new(69915000),
+            cep_end: Cep::new(69915999),
+        };
+        assert!(range.contains(Cep::new(69915000)));
+        assert!(range.contains(Cep::new(69915361)));
+        assert!(range.contains(Cep::new(69915999)));
+        assert!(!range.contains(Cep::new(69914999)));
+        assert!(!range.contains(Cep::new(69916000)));
+    }
+}
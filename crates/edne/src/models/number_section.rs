@@ -0,0 +1,167 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+use std::{error::Error, fmt, str::FromStr};
+
+use crate::models::AddressId;
+
+/// Side of the street covered by a numbering section.
+///
+/// - `Both`: Both sides of the street (A - Ambos)
+/// - `Even`: Even numbers only (P - Par)
+/// - `Odd`: Odd numbers only (I - Ímpar)
+/// - `Right`: Right side of the street (D - Direito)
+/// - `Left`: Left side of the street (E - Esquerdo)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SectionSide {
+    /// Both sides (SEC_IN_LADO = A).
+    Both,
+    /// Even side (SEC_IN_LADO = P).
+    Even,
+    /// Odd side (SEC_IN_LADO = I).
+    Odd,
+    /// Right side (SEC_IN_LADO = D).
+    Right,
+    /// Left side (SEC_IN_LADO = E).
+    Left,
+}
+
+impl SectionSide {
+    /// Returns `true` if a house `number` can be on this side.
+    ///
+    /// Only `Even` and `Odd` restrict the number; the other sides cannot be
+    /// told apart from the number alone.
+    pub fn accepts(&self, number: u32) -> bool {
+        match self {
+            Self::Even => number.is_multiple_of(2),
+            Self::Odd => !number.is_multiple_of(2),
+            Self::Both | Self::Right | Self::Left => true,
+        }
+    }
+}
+
+impl FromStr for SectionSide {
+    type Err = SectionSideError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_uppercase().as_str() {
+            "A" => Ok(Self::Both),
+            "P" => Ok(Self::Even),
+            "I" => Ok(Self::Odd),
+            "D" => Ok(Self::Right),
+            "E" => Ok(Self::Left),
+            other => Err(SectionSideError::InvalidCode(other.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for SectionSide {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let code = match self {
+            Self::Both => "A",
+            Self::Even => "P",
+            Self::Odd => "I",
+            Self::Right => "D",
+            Self::Left => "E",
+        };
+        write!(f, "{}", code)
+    }
+}
+
+/// Errors when parsing `SectionSide`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SectionSideError {
+    /// Invalid side code.
+    InvalidCode(String),
+}
+
+impl fmt::Display for SectionSideError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCode(s) => {
+                write!(f, "invalid section side code: '{}'", s)
+            }
+        }
+    }
+}
+
+impl Error for SectionSideError {}
+
+/// Represents a numbering section of a street from the eDNE database.
+///
+/// Long streets are split into several LOG_LOGRADOURO records, each with
+/// its own CEP. LOG_NUM_SEC tells which house numbers each record covers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NumberSection {
+    /// Address ID of the street segment (LOG_NU).
+    pub address_id: AddressId,
+    /// First house number of the section (SEC_NU_INI).
+    pub number_start: u32,
+    /// Last house number of the section (SEC_NU_FIM).
+    pub number_end: u32,
+    /// Side of the street covered by the section (SEC_IN_LADO).
+    pub side: SectionSide,
+}
+
+impl NumberSection {
+    /// Returns `true` if the house `number` belongs to this section.
+    pub fn contains(&self, number: u32) -> bool {
+        (self.number_start..=self.number_end).contains(&number)
+            && self.side.accepts(number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn section_side_from_str_valid() {
+        assert_eq!(SectionSide::from_str("A").unwrap(), SectionSide::Both);
+        assert_eq!(SectionSide::from_str("P").unwrap(), SectionSide::Even);
+        assert_eq!(SectionSide::from_str("I").unwrap(), SectionSide::Odd);
+        assert_eq!(SectionSide::from_str("D").unwrap(), SectionSide::Right);
+        assert_eq!(SectionSide::from_str("e").unwrap(), SectionSide::Left);
+    }
+
+    #[test]
+    fn section_side_from_str_invalid() {
+        let result = SectionSide::from_str("X");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn section_side_display() {
+        assert_eq!(SectionSide::Both.to_string(), "A");
+        assert_eq!(SectionSide::Even.to_string(), "P");
+        assert_eq!(SectionSide::Odd.to_string(), "I");
+    }
+
+    #[test]
+    fn number_section_contains() {
+        let section = NumberSection {
+            address_id: AddressId::new(1),
+            number_start: 1001,
+            number_end: 1999,
+            side: SectionSide::Even,
+        };
+        assert!(section.contains(1578));
+        assert!(!section.contains(1579));
+        assert!(!section.contains(2000));
+        assert!(!section.contains(998));
+    }
+}
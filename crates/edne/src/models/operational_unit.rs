@@ -16,7 +16,7 @@
 
 use std::{error::Error, fmt, str::FromStr};
 
-use crate::models::{LocalityId, NeighborhoodId, StreetId, Uf};
+use crate::models::{Cep, LocalityId, NeighborhoodId, StreetId, Uf};
 
 /// Unique identifier for an operational unit.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -137,6 +137,7 @@ impl Error for PostBoxIndicatorError {}
 /// centers, etc. For non-coded localities (LOC_IN_SIT=0), the LOG_NU field
 /// is empty and UOP_ENDERECO should be used for addressing.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OperationalUnit {
     /// Unique identifier for the operational unit (UOP_NU).
     pub id: OperationalUnitId,
@@ -153,13 +154,63 @@ pub struct OperationalUnit {
     /// Address of the operational unit (UOP_ENDERECO).
     pub address: String,
     /// Postal code (CEP).
-    pub cep: String,
+    pub cep: Cep,
     /// Post box indicator (UOP_IN_CP).
     pub post_box_indicator: PostBoxIndicator,
     /// Abbreviated name (UOP_NO_ABREV) - optional.
     pub abbreviated_name: Option<String>,
 }
 
+/// Borrowed variant of [`OperationalUnit`] whose text fields are slices of the
+/// decoded line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OperationalUnitRef<'a> {
+    /// Unique identifier for the operational unit (UOP_NU).
+    pub id: OperationalUnitId,
+    /// Federative unit abbreviation (UFE_SG).
+    pub uf: Uf,
+    /// Locality ID (LOC_NU).
+    pub locality_id: LocalityId,
+    /// Neighborhood ID (BAI_NU).
+    pub neighborhood_id: NeighborhoodId,
+    /// Street ID (LOG_NU) - optional, empty for non-coded localities.
+    pub street_id: Option<StreetId>,
+    /// Name of the operational unit (UOP_NO).
+    pub name: &'a str,
+    /// Address of the operational unit (UOP_ENDERECO).
+    pub address: &'a str,
+    /// Postal code (CEP).
+    pub cep: Cep,
+    /// Post box indicator (UOP_IN_CP).
+    pub post_box_indicator: PostBoxIndicator,
+    /// Abbreviated name (UOP_NO_ABREV) - optional.
+    pub abbreviated_name: Option<&'a str>,
+}
+
+impl OperationalUnitRef<'_> {
+    /// Converts into an owned [`OperationalUnit`], allocating its text fields.
+    pub fn to_owned(&self) -> OperationalUnit {
+        OperationalUnit {
+            id: self.id,
+            uf: self.uf,
+            locality_id: self.locality_id,
+            neighborhood_id: self.neighborhood_id,
+            street_id: self.street_id,
+            name: self.name.to_string(),
+            address: self.address.to_string(),
+            cep: self.cep,
+            post_box_indicator: self.post_box_indicator,
+            abbreviated_name: self.abbreviated_name.map(str::to_string),
+        }
+    }
+}
+
+impl From<OperationalUnitRef<'_>> for OperationalUnit {
+    fn from(record: OperationalUnitRef<'_>) -> Self {
+        record.to_owned()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
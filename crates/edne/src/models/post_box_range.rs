@@ -0,0 +1,87 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+use crate::models::{CpcId, OperationalUnitId};
+
+/// Represents a post box number range of an operational unit from the eDNE
+/// database (LOG_FAIXA_UOP).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OperationalUnitPostBoxRange {
+    /// Operational unit ID this range belongs to (UOP_NU).
+    pub operational_unit_id: OperationalUnitId,
+    /// First post box number of the range (FNC_INICIAL).
+    pub box_start: u32,
+    /// Last post box number of the range (FNC_FINAL).
+    pub box_end: u32,
+}
+
+impl OperationalUnitPostBoxRange {
+    /// Returns `true` if the post box `number` falls inside this range.
+    pub fn contains(&self, number: u32) -> bool {
+        (self.box_start..=self.box_end).contains(&number)
+    }
+}
+
+/// Represents a post box number range of a community postal box from the
+/// eDNE database (LOG_FAIXA_CPC).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CpcPostBoxRange {
+    /// CPC ID this range belongs to (CPC_NU).
+    pub cpc_id: CpcId,
+    /// First post box number of the range (CPC_INICIAL).
+    pub box_start: u32,
+    /// Last post box number of the range (CPC_FINAL).
+    pub box_end: u32,
+}
+
+impl CpcPostBoxRange {
+    /// Returns `true` if the post box `number` falls inside this range.
+    pub fn contains(&self, number: u32) -> bool {
+        (self.box_start..=self.box_end).contains(&number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operational_unit_post_box_range_contains() {
+        let range = OperationalUnitPostBoxRange {
+            operational_unit_id: OperationalUnitId::new(1),
+            box_start: 1,
+            box_end: 500,
+        };
+        assert!(range.contains(1));
+        assert!(range.contains(500));
+        assert!(!range.contains(0));
+        assert!(!range.contains(501));
+    }
+
+    #[test]
+    fn cpc_post_box_range_contains() {
+        let range = CpcPostBoxRange {
+            cpc_id: CpcId::new(1285),
+            box_start: 100,
+            box_end: 199,
+        };
+        assert!(range.contains(150));
+        assert!(!range.contains(99));
+        assert!(!range.contains(200));
+    }
+}
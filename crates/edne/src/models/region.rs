@@ -0,0 +1,129 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+use std::{error::Error, fmt, str::FromStr};
+
+use crate::models::Uf;
+
+/// Brazilian geographic regions, as defined by IBGE.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
+pub enum Region {
+    /// North: AC, AM, AP, PA, RO, RR and TO.
+    Norte,
+    /// Northeast: AL, BA, CE, MA, PB, PE, PI, RN and SE.
+    Nordeste,
+    /// Central-West: DF, GO, MS and MT.
+    CentroOeste,
+    /// Southeast: ES, MG, RJ and SP.
+    Sudeste,
+    /// South: PR, RS and SC.
+    Sul,
+}
+
+impl Region {
+    /// Returns the Portuguese name of the region.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Norte => "Norte",
+            Self::Nordeste => "Nordeste",
+            Self::CentroOeste => "Centro-Oeste",
+            Self::Sudeste => "Sudeste",
+            Self::Sul => "Sul",
+        }
+    }
+
+    /// Returns the federative units of the region.
+    pub fn ufs(self) -> impl Iterator<Item = Uf> {
+        Uf::iter().filter(move |uf| uf.region() == self)
+    }
+
+    /// Returns an iterator over all `Region` variants.
+    #[inline]
+    pub fn iter() -> impl Iterator<Item = Region> {
+        [
+            Region::Norte,
+            Region::Nordeste,
+            Region::CentroOeste,
+            Region::Sudeste,
+            Region::Sul,
+        ]
+        .into_iter()
+    }
+}
+
+impl FromStr for Region {
+    type Err = RegionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        Self::iter()
+            .find(|region| region.name().eq_ignore_ascii_case(trimmed))
+            .ok_or_else(|| RegionParseError::InvalidName(trimmed.to_string()))
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Errors when parsing `Region`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegionParseError {
+    /// Name not recognized among the five regions.
+    InvalidName(String),
+}
+
+impl fmt::Display for RegionParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidName(s) => write!(f, "invalid region name: '{}'", s),
+        }
+    }
+}
+
+impl Error for RegionParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn region_from_str() {
+        assert_eq!(Region::from_str("Sul").unwrap(), Region::Sul);
+        assert_eq!(
+            Region::from_str(" centro-oeste ").unwrap(),
+            Region::CentroOeste
+        );
+        assert!(Region::from_str("Oeste").is_err());
+    }
+
+    #[test]
+    fn region_display() {
+        assert_eq!(Region::CentroOeste.to_string(), "Centro-Oeste");
+        assert_eq!(Region::Nordeste.to_string(), "Nordeste");
+    }
+
+    #[test]
+    fn region_ufs() {
+        let sul: Vec<_> = Region::Sul.ufs().collect();
+        assert_eq!(sul, [Uf::PR, Uf::RS, Uf::SC]);
+        assert_eq!(Region::Norte.ufs().count(), 7);
+        assert_eq!(Region::Nordeste.ufs().count(), 9);
+        assert_eq!(Region::iter().flat_map(|r| r.ufs()).count(), 27);
+    }
+}
@@ -0,0 +1,391 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+use std::{
+    collections::HashMap, convert::Infallible, fmt, str::FromStr,
+    sync::LazyLock,
+};
+
+/// Declares [`StreetType`] from the rows of the Correios street type (TLO)
+/// table: variant, full name, abbreviation and TLO code.
+macro_rules! street_types {
+    (
+        $(
+            $variant:ident =>
+                $name:literal, $abbreviation:literal, $code:literal;
+        )+
+    ) => {
+        /// Street type of an address (TLO_TX), from the Correios vocabulary.
+        ///
+        /// Types missing from the vocabulary are kept verbatim in
+        /// [`StreetType::Other`], so parsing never fails.
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum StreetType {
+            $(
+                #[doc = concat!($name, ": ", $abbreviation, " (", $code, ").")]
+                $variant,
+            )+
+            /// Any type outside the vocabulary, as written in the source.
+            Other(String),
+        }
+
+        impl StreetType {
+            /// All street types of the vocabulary, without
+            /// [`StreetType::Other`].
+            pub const KNOWN: &[StreetType] = &[$(Self::$variant),+];
+
+            /// Returns the full Portuguese name, as written in the eDNE
+            /// files.
+            pub fn full_name(&self) -> &str {
+                match self {
+                    $(Self::$variant => $name,)+
+                    Self::Other(name) => name,
+                }
+            }
+
+            /// Returns the abbreviation used in addresses, e.g. "Av." for
+            /// [`StreetType::Avenida`], or `None` for
+            /// [`StreetType::Other`].
+            pub fn abbreviation(&self) -> Option<&'static str> {
+                match self {
+                    $(Self::$variant => Some($abbreviation),)+
+                    Self::Other(_) => None,
+                }
+            }
+
+            /// Returns the Correios TLO code, e.g. "AV" for
+            /// [`StreetType::Avenida`], or `None` for
+            /// [`StreetType::Other`].
+            pub fn code(&self) -> Option<&'static str> {
+                match self {
+                    $(Self::$variant => Some($code),)+
+                    Self::Other(_) => None,
+                }
+            }
+        }
+    };
+}
+
+street_types! {
+    Acampamento => "Acampamento", "Aca.", "ACA";
+    Acesso => "Acesso", "Ac.", "AC";
+    AcessoLocal => "Acesso Local", "Acl.", "ACL";
+    Adro => "Adro", "Ad.", "AD";
+    Aeroporto => "Aeroporto", "Aer.", "AER";
+    Alameda => "Alameda", "Al.", "AL";
+    Alto => "Alto", "At.", "AT";
+    AnelViario => "Anel Viário", "An.", "AN";
+    AntigaEstrada => "Antiga Estrada", "Ant.", "ANT";
+    Area => "Área", "A.", "A";
+    AreaEspecial => "Área Especial", "Ae.", "AE";
+    Arteria => "Artéria", "Art.", "ART";
+    Atalho => "Atalho", "Atl.", "ATL";
+    Avenida => "Avenida", "Av.", "AV";
+    AvenidaContorno => "Avenida Contorno", "Avc.", "AVC";
+    AvenidaMarginal => "Avenida Marginal", "Avm.", "AVM";
+    AvenidaMarginalDireita => "Avenida Marginal Direita", "Amd.", "AMD";
+    AvenidaMarginalEsquerda => "Avenida Marginal Esquerda", "Ame.", "AME";
+    AvenidaVelha => "Avenida Velha", "Avv.", "AVV";
+    Baixa => "Baixa", "Bx.", "BX";
+    Balao => "Balão", "Blo.", "BLO";
+    Balneario => "Balneário", "Bal.", "BAL";
+    Beco => "Beco", "Bc.", "BC";
+    Belvedere => "Belvedere", "Bel.", "BEL";
+    Bloco => "Bloco", "Bl.", "BL";
+    Blocos => "Blocos", "Bls.", "BLS";
+    Bosque => "Bosque", "Bsq.", "BSQ";
+    Boulevard => "Boulevard", "Bvd.", "BVD";
+    Buraco => "Buraco", "Bco.", "BCO";
+    Cais => "Cais", "C.", "C";
+    Calcada => "Calçada", "Cal.", "CAL";
+    Caminho => "Caminho", "Cam.", "CAM";
+    Campo => "Campo", "Cpo.", "CPO";
+    Canal => "Canal", "Can.", "CAN";
+    Chacara => "Chácara", "Ch.", "CH";
+    Chapadao => "Chapadão", "Cha.", "CHA";
+    Ciclovia => "Ciclovia", "Cic.", "CIC";
+    Circular => "Circular", "Cir.", "CIR";
+    Colonia => "Colônia", "Col.", "COL";
+    ComplexoViario => "Complexo Viário", "Cmp.", "CMP";
+    Comunidade => "Comunidade", "Com.", "COM";
+    Condominio => "Condomínio", "Con.", "CON";
+    Conjunto => "Conjunto", "Cj.", "CJ";
+    ConjuntoMutirao => "Conjunto Mutirão", "Cjm.", "CJM";
+    Contorno => "Contorno", "Ctn.", "CTN";
+    Corredor => "Corredor", "Cor.", "COR";
+    Corrego => "Córrego", "Crg.", "CRG";
+    Descida => "Descida", "Dsc.", "DSC";
+    Desvio => "Desvio", "Dsv.", "DSV";
+    Distrito => "Distrito", "Dt.", "DT";
+    EixoIndustrial => "Eixo Industrial", "Ex.", "EX";
+    Elevada => "Elevada", "Evd.", "EVD";
+    Enseada => "Enseada", "Ens.", "ENS";
+    EntradaParticular => "Entrada Particular", "Ent.", "ENT";
+    EntreBloco => "Entre Bloco", "Eb.", "EB";
+    EntreQuadra => "Entre Quadra", "Eq.", "EQ";
+    Escada => "Escada", "Esc.", "ESC";
+    Escadaria => "Escadaria", "Esd.", "ESD";
+    Esplanada => "Esplanada", "Esp.", "ESP";
+    Estacao => "Estação", "Etc.", "ETC";
+    Estacionamento => "Estacionamento", "Ett.", "ETT";
+    Estadio => "Estádio", "Etd.", "ETD";
+    Estancia => "Estância", "Etn.", "ETN";
+    Estrada => "Estrada", "Est.", "EST";
+    EstradaAntiga => "Estrada Antiga", "Eta.", "ETA";
+    EstradaDeLigacao => "Estrada de Ligação", "Esl.", "ESL";
+    EstradaDeServidao => "Estrada de Servidão", "Ess.", "ESS";
+    EstradaEstadual => "Estrada Estadual", "Ese.", "ESE";
+    EstradaIntermunicipal => "Estrada Intermunicipal", "Eim.", "EIM";
+    EstradaMunicipal => "Estrada Municipal", "Esm.", "ESM";
+    EstradaParticular => "Estrada Particular", "Etp.", "ETP";
+    EstradaVelha => "Estrada Velha", "Esv.", "ESV";
+    EstradaVicinal => "Estrada Vicinal", "Esi.", "ESI";
+    Favela => "Favela", "Fav.", "FAV";
+    Fazenda => "Fazenda", "Faz.", "FAZ";
+    Feira => "Feira", "Fra.", "FRA";
+    Ferrovia => "Ferrovia", "Fer.", "FER";
+    Fonte => "Fonte", "Fnt.", "FNT";
+    Forte => "Forte", "Fte.", "FTE";
+    Galeria => "Galeria", "Gal.", "GAL";
+    Granja => "Granja", "Gja.", "GJA";
+    Ilha => "Ilha", "Ia.", "IA";
+    Ilhota => "Ilhota", "Ioa.", "IOA";
+    Jardim => "Jardim", "Jd.", "JD";
+    Jardinete => "Jardinete", "Jde.", "JDE";
+    Ladeira => "Ladeira", "Ld.", "LD";
+    Lago => "Lago", "Lgo.", "LGO";
+    Lagoa => "Lagoa", "Lga.", "LGA";
+    Largo => "Largo", "Lrg.", "LRG";
+    Lote => "Lote", "Lt.", "LT";
+    Loteamento => "Loteamento", "Lot.", "LOT";
+    Margem => "Margem", "Mrg.", "MRG";
+    Marina => "Marina", "Mna.", "MNA";
+    Mercado => "Mercado", "Mer.", "MER";
+    Modulo => "Módulo", "Mod.", "MOD";
+    Monte => "Monte", "Mte.", "MTE";
+    Morro => "Morro", "Mro.", "MRO";
+    Nucleo => "Núcleo", "Nuc.", "NUC";
+    NucleoHabitacional => "Núcleo Habitacional", "Hab.", "HAB";
+    NucleoRural => "Núcleo Rural", "Nur.", "NUR";
+    Outeiro => "Outeiro", "Out.", "OUT";
+    Parada => "Parada", "Pda.", "PDA";
+    Paradouro => "Paradouro", "Pdo.", "PDO";
+    Paralela => "Paralela", "Par.", "PAR";
+    Parque => "Parque", "Prq.", "PRQ";
+    ParqueMunicipal => "Parque Municipal", "Prm.", "PRM";
+    ParqueResidencial => "Parque Residencial", "Prr.", "PRR";
+    Passagem => "Passagem", "Psg.", "PSG";
+    PassagemDePedestre => "Passagem de Pedestre", "Psp.", "PSP";
+    PassagemSubterranea => "Passagem Subterrânea", "Pss.", "PSS";
+    Passarela => "Passarela", "Psa.", "PSA";
+    Passeio => "Passeio", "Pas.", "PAS";
+    Patio => "Pátio", "Pat.", "PAT";
+    Ponta => "Ponta", "Pnt.", "PNT";
+    Ponte => "Ponte", "Pte.", "PTE";
+    Porto => "Porto", "Pto.", "PTO";
+    Praca => "Praça", "Pç.", "PC";
+    PracaDeEsportes => "Praça de Esportes", "Pce.", "PCE";
+    Praia => "Praia", "Pr.", "PR";
+    Prolongamento => "Prolongamento", "Prl.", "PRL";
+    Quadra => "Quadra", "Q.", "Q";
+    Quinta => "Quinta", "Qta.", "QTA";
+    Quintas => "Quintas", "Qts.", "QTS";
+    Ramal => "Ramal", "Ram.", "RAM";
+    Rampa => "Rampa", "Rmp.", "RMP";
+    Recanto => "Recanto", "Rec.", "REC";
+    Recreio => "Recreio", "Rcr.", "RCR";
+    Residencial => "Residencial", "Res.", "RES";
+    Reta => "Reta", "Ret.", "RET";
+    Retiro => "Retiro", "Rer.", "RER";
+    Retorno => "Retorno", "Rtn.", "RTN";
+    RodoAnel => "Rodo Anel", "Roa.", "ROA";
+    Rodovia => "Rodovia", "Rod.", "ROD";
+    Rotatoria => "Rotatória", "Rtt.", "RTT";
+    Rotula => "Rótula", "Rot.", "ROT";
+    Rua => "Rua", "R.", "R";
+    RuaDePedestre => "Rua de Pedestre", "Rpe.", "RPE";
+    Ruela => "Ruela", "Rla.", "RLA";
+    SegundaAvenida => "Segunda Avenida", "Seg.", "SEG";
+    Servidao => "Servidão", "Srv.", "SRV";
+    Setor => "Setor", "St.", "ST";
+    Sitio => "Sítio", "Sit.", "SIT";
+    Subida => "Subida", "Sub.", "SUB";
+    Terminal => "Terminal", "Ter.", "TER";
+    Travessa => "Travessa", "Tv.", "TV";
+    TravessaParticular => "Travessa Particular", "Tvp.", "TVP";
+    TravessaVelha => "Travessa Velha", "Tvv.", "TVV";
+    Trecho => "Trecho", "Tr.", "TR";
+    Trevo => "Trevo", "Trv.", "TRV";
+    Trincheira => "Trincheira", "Tch.", "TCH";
+    Tunel => "Túnel", "Tun.", "TUN";
+    Unidade => "Unidade", "Uni.", "UNI";
+    Vala => "Vala", "Val.", "VAL";
+    Vale => "Vale", "Vle.", "VLE";
+    Variante => "Variante", "Vrt.", "VRT";
+    Vereda => "Vereda", "Ver.", "VER";
+    Via => "Via", "V.", "V";
+    ViaCosteira => "Via Costeira", "Vco.", "VCO";
+    ViaDeAcesso => "Via de Acesso", "Vac.", "VAC";
+    ViaDePedestre => "Via de Pedestre", "Vpe.", "VPE";
+    ViaElevado => "Via Elevado", "Vev.", "VEV";
+    ViaLitoranea => "Via Litorânea", "Vlt.", "VLT";
+    Viaduto => "Viaduto", "Vd.", "VD";
+    Viela => "Viela", "Vla.", "VLA";
+    Vila => "Vila", "Vl.", "VL";
+    ZigueZague => "Zigue-Zague", "Zig.", "ZIG";
+}
+
+/// Normalized full names, abbreviations and codes of the vocabulary.
+static LOOKUP: LazyLock<HashMap<String, StreetType>> = LazyLock::new(|| {
+    let mut lookup = HashMap::with_capacity(StreetType::KNOWN.len() * 3);
+    for street_type in StreetType::KNOWN {
+        let keys = [street_type.abbreviation(), street_type.code()];
+        for key in keys.into_iter().flatten() {
+            lookup.insert(normalize(key), street_type.clone());
+        }
+        lookup.insert(normalize(street_type.full_name()), street_type.clone());
+    }
+    lookup
+});
+
+impl From<&str> for StreetType {
+    /// Parses a full name, an abbreviation or a TLO code, ignoring case,
+    /// accents and the abbreviation's trailing dot.
+    fn from(s: &str) -> Self {
+        match LOOKUP.get(&normalize(s)) {
+            Some(street_type) => street_type.clone(),
+            None => Self::Other(s.trim().to_string()),
+        }
+    }
+}
+
+impl FromStr for StreetType {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(s))
+    }
+}
+
+impl fmt::Display for StreetType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.full_name())
+    }
+}
+
+/// Lowercases `s` and strips accents, surrounding whitespace and a
+/// trailing dot.
+fn normalize(s: &str) -> String {
+    s.trim()
+        .trim_end_matches('.')
+        .chars()
+        .flat_map(char::to_lowercase)
+        .map(|c| match c {
+            'á' | 'à' | 'â' | 'ã' | 'ä' => 'a',
+            'é' | 'è' | 'ê' | 'ë' => 'e',
+            'í' | 'ì' | 'î' | 'ï' => 'i',
+            'ó' | 'ò' | 'ô' | 'õ' | 'ö' => 'o',
+            'ú' | 'ù' | 'û' | 'ü' => 'u',
+            'ç' => 'c',
+            other => other,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn street_type_from_full_name() {
+        assert_eq!(StreetType::from("Rua"), StreetType::Rua);
+        assert_eq!(StreetType::from("AVENIDA"), StreetType::Avenida);
+        assert_eq!(StreetType::from(" Praça "), StreetType::Praca);
+        assert_eq!(StreetType::from("praca"), StreetType::Praca);
+    }
+
+    #[test]
+    fn street_type_from_abbreviation() {
+        assert_eq!(StreetType::from("R."), StreetType::Rua);
+        assert_eq!(StreetType::from("Av."), StreetType::Avenida);
+        assert_eq!(StreetType::from("av"), StreetType::Avenida);
+        assert_eq!(StreetType::from("Tv."), StreetType::Travessa);
+        assert_eq!(StreetType::from("Est."), StreetType::Estrada);
+        assert_eq!(StreetType::from("ETC"), StreetType::Estacao);
+        assert_eq!(StreetType::from("LRG"), StreetType::Largo);
+        assert_eq!(StreetType::from("LGO"), StreetType::Lago);
+    }
+
+    #[test]
+    fn street_type_from_multi_word_name() {
+        assert_eq!(StreetType::from("Caminho"), StreetType::Caminho);
+        assert_eq!(
+            StreetType::from("avenida marginal direita"),
+            StreetType::AvenidaMarginalDireita
+        );
+        assert_eq!(
+            StreetType::from("Passagem de Pedestre"),
+            StreetType::PassagemDePedestre
+        );
+        assert_eq!(StreetType::from("Zigue-Zague"), StreetType::ZigueZague);
+    }
+
+    #[test]
+    fn street_type_other() {
+        let other = StreetType::from(" Desconhecido ");
+        assert_eq!(other, StreetType::Other("Desconhecido".to_string()));
+        assert_eq!(other.full_name(), "Desconhecido");
+        assert_eq!(other.abbreviation(), None);
+        assert_eq!(other.code(), None);
+    }
+
+    #[test]
+    fn street_type_names() {
+        assert_eq!(StreetType::Travessa.full_name(), "Travessa");
+        assert_eq!(StreetType::Travessa.abbreviation(), Some("Tv."));
+        assert_eq!(StreetType::Travessa.code(), Some("TV"));
+        assert_eq!(StreetType::Avenida.abbreviation(), Some("Av."));
+        assert_eq!(StreetType::Avenida.code(), Some("AV"));
+        assert_eq!(StreetType::Rua.abbreviation(), Some("R."));
+        assert_eq!(StreetType::Praca.abbreviation(), Some("Pç."));
+        assert_eq!(StreetType::Estacao.to_string(), "Estação");
+        assert_eq!(StreetType::Boulevard.code(), Some("BVD"));
+    }
+
+    #[test]
+    fn street_type_vocabulary_round_trips() {
+        let mut keys = HashMap::new();
+        for street_type in StreetType::KNOWN {
+            let abbreviation = street_type.abbreviation().unwrap();
+            let code = street_type.code().unwrap();
+            assert_eq!(
+                &StreetType::from(street_type.full_name()),
+                street_type
+            );
+            assert_eq!(&StreetType::from(abbreviation), street_type);
+            assert_eq!(&StreetType::from(code), street_type);
+            let owned: HashSet<_> =
+                [street_type.full_name(), abbreviation, code]
+                    .into_iter()
+                    .map(normalize)
+                    .collect();
+            for key in owned {
+                assert_eq!(keys.insert(key, street_type), None);
+            }
+        }
+    }
+}
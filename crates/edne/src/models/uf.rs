@@ -14,7 +14,9 @@
 // OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 //
 
-use std::{error::Error, fmt, str::FromStr};
+use std::{error::Error, fmt, ops::RangeInclusive, str::FromStr};
+
+use crate::models::{Cep, Region};
 
 /// Brazilian federative units (UFE_SG).
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Ord, PartialOrd)]
@@ -49,7 +51,7 @@ pub enum Uf {
 }
 
 /// Parsing errors for `Uf`.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum UfParseError {
     /// Empty input after trimming.
     Empty,
@@ -57,6 +59,8 @@ pub enum UfParseError {
     WrongLength(usize),
     /// Code not recognized among official UF codes.
     InvalidCode(String),
+    /// Number not recognized among IBGE state codes.
+    InvalidIbgeCode(u8),
 }
 
 impl fmt::Display for UfParseError {
@@ -66,6 +70,7 @@ impl fmt::Display for UfParseError {
             Empty => write!(f, "UF code is empty"),
             WrongLength(n) => write!(f, "UF code must have length 2, got {n}"),
             InvalidCode(s) => write!(f, "invalid UF code: {}", s),
+            InvalidIbgeCode(n) => write!(f, "invalid IBGE state code: {n}"),
         }
     }
 }
@@ -159,6 +164,201 @@ impl Uf {
         }
     }
 
+    /// Returns the geographic region of the state.
+    pub fn region(&self) -> Region {
+        use Uf::*;
+        match self {
+            AC => Region::Norte,
+            AL => Region::Nordeste,
+            AP => Region::Norte,
+            AM => Region::Norte,
+            BA => Region::Nordeste,
+            CE => Region::Nordeste,
+            DF => Region::CentroOeste,
+            ES => Region::Sudeste,
+            GO => Region::CentroOeste,
+            MA => Region::Nordeste,
+            MT => Region::CentroOeste,
+            MS => Region::CentroOeste,
+            MG => Region::Sudeste,
+            PA => Region::Norte,
+            PB => Region::Nordeste,
+            PR => Region::Sul,
+            PE => Region::Nordeste,
+            PI => Region::Nordeste,
+            RJ => Region::Sudeste,
+            RN => Region::Nordeste,
+            RS => Region::Sul,
+            RO => Region::Norte,
+            RR => Region::Norte,
+            SC => Region::Sul,
+            SP => Region::Sudeste,
+            SE => Region::Nordeste,
+            TO => Region::Norte,
+        }
+    }
+
+    /// Returns the two-digit IBGE code of the state.
+    pub fn ibge_code(&self) -> u8 {
+        use Uf::*;
+        match self {
+            AC => 12,
+            AL => 27,
+            AP => 16,
+            AM => 13,
+            BA => 29,
+            CE => 23,
+            DF => 53,
+            ES => 32,
+            GO => 52,
+            MA => 21,
+            MT => 51,
+            MS => 50,
+            MG => 31,
+            PA => 15,
+            PB => 25,
+            PR => 41,
+            PE => 26,
+            PI => 22,
+            RJ => 33,
+            RN => 24,
+            RS => 43,
+            RO => 11,
+            RR => 14,
+            SC => 42,
+            SP => 35,
+            SE => 28,
+            TO => 17,
+        }
+    }
+
+    /// Creates a `Uf` from its two-digit IBGE code.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UfParseError::InvalidIbgeCode` if no state has `code`.
+    pub fn from_ibge_code(code: u8) -> Result<Self, UfParseError> {
+        use Uf::*;
+        match code {
+            11 => Ok(RO),
+            12 => Ok(AC),
+            13 => Ok(AM),
+            14 => Ok(RR),
+            15 => Ok(PA),
+            16 => Ok(AP),
+            17 => Ok(TO),
+            21 => Ok(MA),
+            22 => Ok(PI),
+            23 => Ok(CE),
+            24 => Ok(RN),
+            25 => Ok(PB),
+            26 => Ok(PE),
+            27 => Ok(AL),
+            28 => Ok(SE),
+            29 => Ok(BA),
+            31 => Ok(MG),
+            32 => Ok(ES),
+            33 => Ok(RJ),
+            35 => Ok(SP),
+            41 => Ok(PR),
+            42 => Ok(SC),
+            43 => Ok(RS),
+            50 => Ok(MS),
+            51 => Ok(MT),
+            52 => Ok(GO),
+            53 => Ok(DF),
+            _ => Err(UfParseError::InvalidIbgeCode(code)),
+        }
+    }
+
+    /// Returns the name of the state capital.
+    pub fn capital(&self) -> &'static str {
+        use Uf::*;
+        match self {
+            AC => "Rio Branco",
+            AL => "Maceió",
+            AP => "Macapá",
+            AM => "Manaus",
+            BA => "Salvador",
+            CE => "Fortaleza",
+            DF => "Brasília",
+            ES => "Vitória",
+            GO => "Goiânia",
+            MA => "São Luís",
+            MT => "Cuiabá",
+            MS => "Campo Grande",
+            MG => "Belo Horizonte",
+            PA => "Belém",
+            PB => "João Pessoa",
+            PR => "Curitiba",
+            PE => "Recife",
+            PI => "Teresina",
+            RJ => "Rio de Janeiro",
+            RN => "Natal",
+            RS => "Porto Alegre",
+            RO => "Porto Velho",
+            RR => "Boa Vista",
+            SC => "Florianópolis",
+            SP => "São Paulo",
+            SE => "Aracaju",
+            TO => "Palmas",
+        }
+    }
+
+    /// Official CEP ranges of each state, ordered by CEP.
+    ///
+    /// AM, DF and GO own two ranges each.
+    pub const CEP_RANGES: &'static [(Uf, RangeInclusive<Cep>)] = &[
+        (Uf::SP, Cep::new(1_000_000)..=Cep::new(19_999_999)),
+        (Uf::RJ, Cep::new(20_000_000)..=Cep::new(28_999_999)),
+        (Uf::ES, Cep::new(29_000_000)..=Cep::new(29_999_999)),
+        (Uf::MG, Cep::new(30_000_000)..=Cep::new(39_999_999)),
+        (Uf::BA, Cep::new(40_000_000)..=Cep::new(48_999_999)),
+        (Uf::SE, Cep::new(49_000_000)..=Cep::new(49_999_999)),
+        (Uf::PE, Cep::new(50_000_000)..=Cep::new(56_999_999)),
+        (Uf::AL, Cep::new(57_000_000)..=Cep::new(57_999_999)),
+        (Uf::PB, Cep::new(58_000_000)..=Cep::new(58_999_999)),
+        (Uf::RN, Cep::new(59_000_000)..=Cep::new(59_999_999)),
+        (Uf::CE, Cep::new(60_000_000)..=Cep::new(63_999_999)),
+        (Uf::PI, Cep::new(64_000_000)..=Cep::new(64_999_999)),
+        (Uf::MA, Cep::new(65_000_000)..=Cep::new(65_999_999)),
+        (Uf::PA, Cep::new(66_000_000)..=Cep::new(68_899_999)),
+        (Uf::AP, Cep::new(68_900_000)..=Cep::new(68_999_999)),
+        (Uf::AM, Cep::new(69_000_000)..=Cep::new(69_299_999)),
+        (Uf::RR, Cep::new(69_300_000)..=Cep::new(69_399_999)),
+        (Uf::AM, Cep::new(69_400_000)..=Cep::new(69_899_999)),
+        (Uf::AC, Cep::new(69_900_000)..=Cep::new(69_999_999)),
+        (Uf::DF, Cep::new(70_000_000)..=Cep::new(72_799_999)),
+        (Uf::GO, Cep::new(72_800_000)..=Cep::new(72_999_999)),
+        (Uf::DF, Cep::new(73_000_000)..=Cep::new(73_699_999)),
+        (Uf::GO, Cep::new(73_700_000)..=Cep::new(76_799_999)),
+        (Uf::RO, Cep::new(76_800_000)..=Cep::new(76_999_999)),
+        (Uf::TO, Cep::new(77_000_000)..=Cep::new(77_999_999)),
+        (Uf::MT, Cep::new(78_000_000)..=Cep::new(78_899_999)),
+        (Uf::MS, Cep::new(79_000_000)..=Cep::new(79_999_999)),
+        (Uf::PR, Cep::new(80_000_000)..=Cep::new(87_999_999)),
+        (Uf::SC, Cep::new(88_000_000)..=Cep::new(89_999_999)),
+        (Uf::RS, Cep::new(90_000_000)..=Cep::new(99_999_999)),
+    ];
+
+    /// Returns the official CEP ranges of the state.
+    pub fn cep_ranges(
+        self,
+    ) -> impl Iterator<Item = &'static RangeInclusive<Cep>> {
+        Self::CEP_RANGES
+            .iter()
+            .filter(move |(uf, _)| *uf == self)
+            .map(|(_, range)| range)
+    }
+
+    /// Returns the state whose official CEP ranges contain `cep`.
+    pub fn from_cep(cep: Cep) -> Option<Self> {
+        Self::CEP_RANGES
+            .iter()
+            .find(|(_, range)| range.contains(&cep))
+            .map(|(uf, _)| *uf)
+    }
+
     /// Returns an iterator over all `Uf` variants.
     ///
     /// Note: For arrays, `into_iter()` yields items by value, so no `.copied()` is needed.
@@ -252,4 +452,62 @@ mod tests {
         assert!(all.contains(&Uf::AC));
         assert!(all.contains(&Uf::TO));
     }
+
+    /// Ensures regions group the states as defined by IBGE.
+    #[test]
+    fn uf_region_mapping() {
+        assert_eq!(Uf::AC.region(), Region::Norte);
+        assert_eq!(Uf::BA.region(), Region::Nordeste);
+        assert_eq!(Uf::DF.region(), Region::CentroOeste);
+        assert_eq!(Uf::SP.region(), Region::Sudeste);
+        assert_eq!(Uf::RS.region(), Region::Sul);
+    }
+
+    /// Validates that IBGE codes round-trip and are unique.
+    #[test]
+    fn uf_ibge_code_round_trip() {
+        assert_eq!(Uf::SP.ibge_code(), 35);
+        assert_eq!(Uf::RO.ibge_code(), 11);
+        assert_eq!(Uf::DF.ibge_code(), 53);
+        for uf in Uf::iter() {
+            assert_eq!(Uf::from_ibge_code(uf.ibge_code()), Ok(uf));
+        }
+        assert_eq!(
+            Uf::from_ibge_code(34),
+            Err(UfParseError::InvalidIbgeCode(34))
+        );
+    }
+
+    /// Ensures the capital mapping works correctly.
+    #[test]
+    fn uf_capital_mapping() {
+        assert_eq!(Uf::AC.capital(), "Rio Branco");
+        assert_eq!(Uf::SC.capital(), "Florianópolis");
+        assert_eq!(Uf::DF.capital(), "Brasília");
+    }
+
+    /// Validates that the CEP ranges are ordered and never overlap.
+    #[test]
+    fn uf_cep_ranges_are_ordered() {
+        for pair in Uf::CEP_RANGES.windows(2) {
+            let (previous, next) = (&pair[0].1, &pair[1].1);
+            assert!(previous.start() <= previous.end());
+            assert!(previous.end() < next.start());
+        }
+        for uf in Uf::iter() {
+            assert!(uf.cep_ranges().count() > 0, "{uf} has no CEP range");
+        }
+    }
+
+    /// Ensures CEPs resolve to the state owning their range.
+    #[test]
+    fn uf_from_cep() {
+        assert_eq!(Uf::from_cep(Cep::new(1_310_100)), Some(Uf::SP));
+        assert_eq!(Uf::from_cep(Cep::new(69_918_703)), Some(Uf::AC));
+        assert_eq!(Uf::from_cep(Cep::new(69_300_000)), Some(Uf::RR));
+        assert_eq!(Uf::from_cep(Cep::new(72_850_000)), Some(Uf::GO));
+        assert_eq!(Uf::from_cep(Cep::new(73_010_000)), Some(Uf::DF));
+        assert_eq!(Uf::from_cep(Cep::new(999_999)), None);
+        assert_eq!(Uf::DF.cep_ranges().count(), 2);
+    }
 }
@@ -0,0 +1,67 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+use crate::models::{Cep, Uf};
+
+/// Represents a CEP range of a federative unit from the eDNE database.
+///
+/// A state may own more than one range, so LOG_FAIXA_UF can hold several
+/// records for the same UF.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UfCepRange {
+    /// Federative unit abbreviation (UFE_SG).
+    pub uf: Uf,
+    /// First CEP of the range (UFE_CEP_INI).
+    pub cep_start: Cep,
+    /// Last CEP of the range (UFE_CEP_FIM).
+    pub cep_end: Cep,
+}
+
+impl UfCepRange {
+    /// Returns `true` if `cep` falls inside this range (inclusive).
+    pub fn contains(&self, cep: Cep) -> bool {
+        self.cep_start <= cep && cep <= self.cep_end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn acre() -> UfCepRange {
+        UfCepRange {
+            uf: Uf::AC,
+            cep_start: Cep::new(69900000),
+            cep_end: Cep::new(69999999),
+        }
+    }
+
+    #[test]
+    fn uf_cep_range_contains_bounds() {
+        let range = acre();
+        assert!(range.contains(Cep::new(69900000)));
+        assert!(range.contains(Cep::new(69918703)));
+        assert!(range.contains(Cep::new(69999999)));
+    }
+
+    #[test]
+    fn uf_cep_range_outside() {
+        let range = acre();
+        assert!(!range.contains(Cep::new(69899999)));
+        assert!(!range.contains(Cep::new(70000000)));
+    }
+}
@@ -0,0 +1,251 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+use std::{collections::HashMap, str::FromStr};
+
+use crate::{
+    models::{AddressId, StreetType, name_variant::AddressNameVariant},
+    parser::{
+        base::{EdneParser, ParseError},
+        collection::Collection,
+        record_stream::Record,
+    },
+};
+
+/// Expected number of fields in a address name variant record.
+const ADDRESS_VARIANT_FIELD_COUNT: usize = 4;
+
+/// Collection of address name variants indexed by their address ID.
+#[derive(Debug, Clone)]
+pub struct AddressNameVariants(HashMap<AddressId, Vec<AddressNameVariant>>);
+
+impl AddressNameVariants {
+    /// Creates a new empty collection.
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Creates a collection with pre-allocated capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(HashMap::with_capacity(capacity))
+    }
+
+    /// Returns the number of variants.
+    pub fn len(&self) -> usize {
+        self.0.values().map(Vec::len).sum()
+    }
+
+    /// Returns `true` if the collection is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Gets all variants of an address.
+    pub fn get(&self, id: &AddressId) -> Option<&[AddressNameVariant]> {
+        self.0.get(id).map(Vec::as_slice)
+    }
+
+    /// Inserts a variant into the collection.
+    pub fn insert(&mut self, variant: AddressNameVariant) {
+        self.0.entry(variant.address_id).or_default().push(variant);
+    }
+
+    /// Returns an iterator over all variants.
+    pub fn iter(&self) -> impl Iterator<Item = &AddressNameVariant> {
+        self.0.values().flatten()
+    }
+
+    /// Returns an iterator over the variants whose name matches `name`,
+    /// ignoring case.
+    pub fn find_by_name(
+        &self,
+        name: &str,
+    ) -> impl Iterator<Item = &AddressNameVariant> {
+        let name = name.trim().to_lowercase();
+        self.iter().filter(move |variant| variant.name.to_lowercase() == name)
+    }
+}
+
+impl Default for AddressNameVariants {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collection for AddressNameVariants {
+    type Record = AddressNameVariant;
+    const ARCHIVE_ENTRY: &'static str = "LOG_VAR_LOG.TXT";
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity(capacity)
+    }
+}
+
+impl Extend<AddressNameVariant> for AddressNameVariants {
+    fn extend<I: IntoIterator<Item = AddressNameVariant>>(&mut self, iter: I) {
+        for record in iter {
+            self.insert(record);
+        }
+    }
+}
+
+impl FromIterator<AddressNameVariant> for AddressNameVariants {
+    fn from_iter<I: IntoIterator<Item = AddressNameVariant>>(iter: I) -> Self {
+        let mut records = Self::new();
+        records.extend(iter);
+        records
+    }
+}
+
+impl Record for AddressNameVariant {
+    const FIELDS: &'static [&'static str] =
+        &["LOG_NU", "VLO_NU", "TLO_TX", "VLO_TX"];
+
+    fn parse_line(
+        parser: &EdneParser,
+        line: &str,
+        line_number: usize,
+    ) -> Result<Self, ParseError> {
+        parse_address_variant_line(parser, line, line_number)
+    }
+}
+
+/// Parses a single LOG_VAR_LOG line into an `AddressNameVariant` struct.
+///
+/// # Field order (4 fields):
+/// 1. LOG_NU - Address ID
+/// 2. VLO_NU - Variant sequence number
+/// 3. TLO_TX - Street type
+/// 4. VLO_TX - Variant name
+fn parse_address_variant_line(
+    parser: &EdneParser,
+    line: &str,
+    line_number: usize,
+) -> Result<AddressNameVariant, ParseError> {
+    let fields = parser.parse_line_checked(
+        line,
+        ADDRESS_VARIANT_FIELD_COUNT,
+        line_number,
+    )?;
+
+    let id_str = EdneParser::required_field(fields[0], "LOG_NU", line_number)?;
+    let address_id = AddressId::from_str(&id_str).map_err(|e| {
+        ParseError::InvalidValue {
+            field_name: "LOG_NU",
+            value: id_str,
+            reason: e.to_string(),
+            source: Some(e.into()),
+            line_number,
+            span: None,
+        }
+    })?;
+
+    let sequence = EdneParser::parse_number(fields[1], "VLO_NU", line_number)?;
+    let street_type = StreetType::from(EdneParser::required_str(
+        fields[2],
+        "TLO_TX",
+        line_number,
+    )?);
+    let name = EdneParser::required_field(fields[3], "VLO_TX", line_number)?;
+
+    Ok(AddressNameVariant { address_id, sequence, street_type, name })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_DATA: &str = "\
+1@1@Rua@Projetada A
+1004886@1@Travessa@Cezário
+1004886@2@Rua@Manoel Cezário";
+
+    #[test]
+    fn parse_sample_data() {
+        let variants =
+            AddressNameVariants::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        assert_eq!(variants.len(), 3);
+    }
+
+    #[test]
+    fn parse_address_with_several_variants() {
+        let variants =
+            AddressNameVariants::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        let cezario = variants.get(&AddressId::new(1004886)).unwrap();
+
+        assert_eq!(cezario.len(), 2);
+        assert_eq!(cezario[0].sequence, 1);
+        assert_eq!(cezario[0].street_type, StreetType::Travessa);
+        assert_eq!(cezario[0].name, "Cezário");
+        assert_eq!(cezario[1].street_type, StreetType::Rua);
+    }
+
+    #[test]
+    fn find_by_name_ignores_case() {
+        let variants =
+            AddressNameVariants::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        let found: Vec<_> = variants.find_by_name("projetada a").collect();
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].address_id, AddressId::new(1));
+    }
+
+    #[test]
+    fn parse_invalid_field_count() {
+        let invalid = "1@1@Projetada A";
+        let result = AddressNameVariants::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::FieldCount { expected, got, .. } => {
+                assert_eq!(expected, 4);
+                assert_eq!(got, 3);
+            }
+            _ => panic!("Expected FieldCount error"),
+        }
+    }
+
+    #[test]
+    fn parse_empty_street_type() {
+        let invalid = "1@1@@Projetada A";
+        let result = AddressNameVariants::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::EmptyField { field_name, .. } => {
+                assert_eq!(field_name, "TLO_TX");
+            }
+            _ => panic!("Expected EmptyField error"),
+        }
+    }
+
+    #[test]
+    fn parse_invalid_variant_fields() {
+        let invalid = "1@x@Rua@Projetada A";
+        let result = AddressNameVariants::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::InvalidNumber { field_name, .. } => {
+                assert_eq!(field_name, "VLO_NU");
+            }
+            _ => panic!("Expected InvalidNumber error"),
+        }
+
+        let invalid = "1@1@Rua@";
+        let result = AddressNameVariants::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::EmptyField { field_name, .. } => {
+                assert_eq!(field_name, "VLO_TX");
+            }
+            _ => panic!("Expected EmptyField error"),
+        }
+    }
+}
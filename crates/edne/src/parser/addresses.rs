@@ -19,9 +19,15 @@ use std::{collections::HashMap, str::FromStr};
 use crate::{
     models::{
         LocalityId, NeighborhoodId, Uf,
-        address::{Address, AddressId, StreetTypeIndicator},
+        address::{Address, AddressId, AddressRef, StreetTypeIndicator},
+    },
+    parser::{
+        archive::Archive,
+        base::{EdneParser, ParseError},
+        collection::{Collection, DeltaCollection},
+        helpers,
+        record_stream::{Record, RecordRef},
     },
-    parser::base::{EdneParser, ParseError},
 };
 
 const ADDRESS_FIELD_COUNT: usize = 11;
@@ -54,36 +60,130 @@ impl Addresses {
         self.0.insert(address.id, address)
     }
 
+    /// Removes an address by ID, returning it if it was present.
+    pub fn remove(&mut self, id: &AddressId) -> Option<Address> {
+        self.0.remove(id)
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = (&AddressId, &Address)> {
         self.0.iter()
     }
+}
+
+impl Default for Addresses {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collection for Addresses {
+    type Record = Address;
+    /// Addresses are split into one entry per UF, with XX replaced by the
+    /// UF code.
+    const ARCHIVE_ENTRY: &'static str = "LOG_LOGRADOURO_XX.TXT";
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity(capacity)
+    }
+
+    /// Parses addresses from all LOG_LOGRADOURO_XX.TXT entries of an eDNE
+    /// archive, one per UF.
+    ///
+    /// UFs missing from the archive are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::Archive` if the archive has no address entry at
+    /// all, or `ParseError` if an entry cannot be extracted or any line has
+    /// invalid data.
+    fn from_archive(archive: &Archive) -> Result<Self, ParseError> {
+        let mut addresses = Self::new();
+        let mut found = false;
+        for uf in Uf::iter() {
+            let file_name = format!("LOG_LOGRADOURO_{uf}.TXT");
+            if !archive.contains(&file_name) {
+                continue;
+            }
+            let parser = archive.parser(&file_name)?;
+            addresses.0.extend(Self::from_parser(&parser)?.0);
+            found = true;
+        }
+        if !found {
+            return Err(ParseError::Archive(format!(
+                "no entry named '{}'",
+                Self::ARCHIVE_ENTRY
+            )));
+        }
+        Ok(addresses)
+    }
+}
+
+impl DeltaCollection for Addresses {
+    type Key = AddressId;
+    const OPERATION_FIELD: &'static str = "LOG_OPERACAO";
 
-    pub fn from_iso8859_1(bytes: &[u8]) -> Result<Self, ParseError> {
-        let parser = EdneParser::from_iso8859_1(bytes)?;
-        Self::parse_with_parser(&parser)
+    fn key(record: &Address) -> AddressId {
+        record.id
     }
 
-    pub fn from_utf8(content: String) -> Result<Self, ParseError> {
-        let parser = EdneParser::from_utf8(content);
-        Self::parse_with_parser(&parser)
+    fn insert(&mut self, record: Address) -> Option<Address> {
+        self.0.insert(record.id, record)
     }
 
-    fn parse_with_parser(parser: &EdneParser) -> Result<Self, ParseError> {
-        let lines: Vec<_> = parser.lines().collect();
-        let mut addresses = Self::with_capacity(lines.len());
+    fn remove(&mut self, key: &AddressId) -> Option<Address> {
+        self.0.remove(key)
+    }
+}
 
-        for (line_number, line) in lines {
-            let address = parse_address_line(parser, line, line_number)?;
-            addresses.insert(address);
+impl Extend<Address> for Addresses {
+    fn extend<I: IntoIterator<Item = Address>>(&mut self, iter: I) {
+        for record in iter {
+            self.insert(record);
         }
+    }
+}
 
-        Ok(addresses)
+impl FromIterator<Address> for Addresses {
+    fn from_iter<I: IntoIterator<Item = Address>>(iter: I) -> Self {
+        let mut records = Self::new();
+        records.extend(iter);
+        records
     }
 }
 
-impl Default for Addresses {
-    fn default() -> Self {
-        Self::new()
+impl Record for Address {
+    const FIELDS: &'static [&'static str] = &[
+        "LOG_NU",
+        "UFE_SG",
+        "LOC_NU",
+        "BAI_NU_INI",
+        "BAI_NU_FIM",
+        "LOG_NO",
+        "LOG_COMPLEMENTO",
+        "CEP",
+        "TLO_TX",
+        "LOG_STA_TLO",
+        "LOG_NO_ABREV",
+    ];
+
+    fn parse_line(
+        parser: &EdneParser,
+        line: &str,
+        line_number: usize,
+    ) -> Result<Self, ParseError> {
+        parse_address_line(parser, line, line_number)
+    }
+}
+
+impl<'a> RecordRef<'a> for AddressRef<'a> {
+    type Owned = Address;
+
+    fn parse_line(
+        parser: &EdneParser,
+        line: &'a str,
+        line_number: usize,
+    ) -> Result<Self, ParseError> {
+        parse_address_ref_line(parser, line, line_number)
     }
 }
 
@@ -92,85 +192,106 @@ fn parse_address_line(
     line: &str,
     line_number: usize,
 ) -> Result<Address, ParseError> {
+    parse_address_ref_line(parser, line, line_number).map(|r| r.to_owned())
+}
+
+/// Parses a single line into a borrowed `AddressRef`, without allocating its
+/// text fields. The field order is the same as [`parse_address_line`].
+fn parse_address_ref_line<'a>(
+    parser: &EdneParser,
+    line: &'a str,
+    line_number: usize,
+) -> Result<AddressRef<'a>, ParseError> {
     let fields =
         parser.parse_line_checked(line, ADDRESS_FIELD_COUNT, line_number)?;
 
-    let id_str = EdneParser::required_field(fields[0], "LOG_NU", line_number)?;
-    let id = AddressId::from_str(&id_str).map_err(|e| {
-        ParseError::InvalidValue {
+    let id_str = EdneParser::required_str(fields[0], "LOG_NU", line_number)?;
+    let id =
+        AddressId::from_str(id_str).map_err(|e| ParseError::InvalidValue {
             field_name: "LOG_NU",
-            value: id_str,
+            value: id_str.to_string(),
             reason: e.to_string(),
+            source: Some(e.into()),
             line_number,
-        }
-    })?;
+            span: None,
+        })?;
 
-    let uf_str = EdneParser::required_field(fields[1], "UFE_SG", line_number)?;
-    let uf = Uf::from_str(&uf_str).map_err(|e| ParseError::InvalidValue {
+    let uf_str = EdneParser::required_str(fields[1], "UFE_SG", line_number)?;
+    let uf = Uf::from_str(uf_str).map_err(|e| ParseError::InvalidValue {
         field_name: "UFE_SG",
-        value: uf_str,
+        value: uf_str.to_string(),
         reason: e.to_string(),
+        source: Some(e.into()),
         line_number,
+        span: None,
     })?;
 
     let loc_id_str =
-        EdneParser::required_field(fields[2], "LOC_NU", line_number)?;
-    let locality_id = LocalityId::from_str(&loc_id_str).map_err(|e| {
+        EdneParser::required_str(fields[2], "LOC_NU", line_number)?;
+    let locality_id = LocalityId::from_str(loc_id_str).map_err(|e| {
         ParseError::InvalidValue {
             field_name: "LOC_NU",
-            value: loc_id_str,
+            value: loc_id_str.to_string(),
             reason: e.to_string(),
+            source: Some(e.into()),
             line_number,
+            span: None,
         }
     })?;
 
     let bai_ini_str =
-        EdneParser::required_field(fields[3], "BAI_NU_INI", line_number)?;
-    let neighborhood_id_start = NeighborhoodId::from_str(&bai_ini_str)
+        EdneParser::required_str(fields[3], "BAI_NU_INI", line_number)?;
+    let neighborhood_id_start = NeighborhoodId::from_str(bai_ini_str)
         .map_err(|e| ParseError::InvalidValue {
             field_name: "BAI_NU_INI",
-            value: bai_ini_str,
+            value: bai_ini_str.to_string(),
             reason: e.to_string(),
+            source: Some(e.into()),
             line_number,
+            span: None,
         })?;
 
     let neighborhood_id_end =
-        if let Some(bai_fim_str) = EdneParser::optional_field(fields[4]) {
-            Some(NeighborhoodId::from_str(&bai_fim_str).map_err(|e| {
+        if let Some(bai_fim_str) = EdneParser::optional_str(fields[4]) {
+            Some(NeighborhoodId::from_str(bai_fim_str).map_err(|e| {
                 ParseError::InvalidValue {
                     field_name: "BAI_NU_FIM",
-                    value: bai_fim_str,
+                    value: bai_fim_str.to_string(),
                     reason: e.to_string(),
+                    source: Some(e.into()),
                     line_number,
+                    span: None,
                 }
             })?)
         } else {
             None
         };
 
-    let name = EdneParser::required_field(fields[5], "LOG_NO", line_number)?;
-    let complement = EdneParser::optional_field(fields[6]);
-    let cep = EdneParser::required_field(fields[7], "CEP", line_number)?;
+    let name = EdneParser::required_str(fields[5], "LOG_NO", line_number)?;
+    let complement = EdneParser::optional_str(fields[6]);
+    let cep = helpers::required_cep(fields[7], "CEP", line_number)?;
     let street_type =
-        EdneParser::required_field(fields[8], "TLO_TX", line_number)?;
+        EdneParser::required_str(fields[8], "TLO_TX", line_number)?;
 
     let street_type_indicator =
-        if let Some(indicator_str) = EdneParser::optional_field(fields[9]) {
-            Some(StreetTypeIndicator::from_str(&indicator_str).map_err(
+        if let Some(indicator_str) = EdneParser::optional_str(fields[9]) {
+            Some(StreetTypeIndicator::from_str(indicator_str).map_err(
                 |e| ParseError::InvalidValue {
                     field_name: "LOG_STA_TLO",
-                    value: indicator_str,
+                    value: indicator_str.to_string(),
                     reason: e.to_string(),
+                    source: Some(e.into()),
                     line_number,
+                    span: None,
                 },
             )?)
         } else {
             None
         };
 
-    let abbreviated_name = EdneParser::optional_field(fields[10]);
+    let abbreviated_name = EdneParser::optional_str(fields[10]);
 
-    Ok(Address {
+    Ok(AddressRef {
         id,
         uf,
         locality_id,
@@ -188,6 +309,8 @@ fn parse_address_line(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::{Cep, StreetType};
+    use crate::parser::{archive::tests::build_zip, base::ErrorKind};
 
     const SAMPLE_DATA: &str = "\
 1@AC@16@47@@Nelson Mesquita@@69918703@Rua@S@R Nelson Mesquita
@@ -225,8 +348,8 @@ mod tests {
         assert_eq!(addr.neighborhood_id_end, None);
         assert_eq!(addr.name, "Nelson Mesquita");
         assert_eq!(addr.complement, None);
-        assert_eq!(addr.cep, "69918703");
-        assert_eq!(addr.street_type, "Rua");
+        assert_eq!(addr.cep, Cep::new(69918703));
+        assert_eq!(addr.street_type, StreetType::Rua);
         assert_eq!(addr.street_type_indicator, Some(StreetTypeIndicator::Yes));
         assert_eq!(
             addr.abbreviated_name,
@@ -238,14 +361,18 @@ mod tests {
     fn parse_street_types() {
         let addresses = Addresses::from_utf8(SAMPLE_DATA.to_string()).unwrap();
 
-        let rua_count =
-            addresses.iter().filter(|(_, a)| a.street_type == "Rua").count();
+        let rua_count = addresses
+            .iter()
+            .filter(|(_, a)| a.street_type == StreetType::Rua)
+            .count();
         let travessa_count = addresses
             .iter()
-            .filter(|(_, a)| a.street_type == "Travessa")
+            .filter(|(_, a)| a.street_type == StreetType::Travessa)
+            .count();
+        let beco_count = addresses
+            .iter()
+            .filter(|(_, a)| a.street_type == StreetType::Beco)
             .count();
-        let beco_count =
-            addresses.iter().filter(|(_, a)| a.street_type == "Beco").count();
 
         assert_eq!(rua_count, 9);
         assert_eq!(travessa_count, 4);
@@ -258,4 +385,68 @@ mod tests {
         let result = Addresses::from_utf8(invalid.to_string());
         assert!(result.is_err());
     }
+
+    const DELTA_DATA: &str = "\
+1@AC@16@47@@Nome Novo@@69918703@Rua@S@R Nelson Mesquita@UPD
+1001866@AC@16@55447@@24 de Dezembro@@69918142@Rua@S@R 24 de Dezembro@DEL
+999999@AC@16@47@@Nelson Mesquita@@69918703@Rua@S@R Nelson Mesquita@INS";
+
+    #[test]
+    fn apply_delta_to_base() {
+        let mut addresses =
+            Addresses::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        let before = addresses.len();
+        let deltas =
+            Addresses::deltas_from_utf8(DELTA_DATA.to_string()).unwrap();
+        assert_eq!(deltas.len(), 3);
+
+        for delta in deltas {
+            addresses.apply_delta(delta);
+        }
+
+        assert_eq!(addresses.len(), before);
+        assert_eq!(
+            addresses.get(&AddressId::new(1)).unwrap().name,
+            "Nome Novo"
+        );
+        assert!(addresses.get(&AddressId::new(1001866)).is_none());
+        assert!(addresses.get(&AddressId::new(999999)).is_some());
+    }
+
+    #[test]
+    fn parse_delta_invalid_operation() {
+        let invalid = "1001866@AC@16@55447@@24 de Dezembro@@69918142@Rua@S@R 24 de Dezembro@XXX";
+        let result = Addresses::deltas_from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::InvalidValue { field_name, .. } => {
+                assert_eq!(field_name, "LOG_OPERACAO");
+            }
+            _ => panic!("Expected InvalidValue error"),
+        }
+    }
+
+    #[test]
+    fn parse_from_archive() {
+        let zip = build_zip(&[
+            ("Delimitado/LOG_LOGRADOURO_AC.TXT", SAMPLE_DATA.as_bytes(), true),
+            (
+                "Delimitado/LOG_LOGRADOURO_SP.TXT",
+                b"9999999@SP@9668@1@@Paulista@@01310100@Avenida@S@Av Paulista",
+                true,
+            ),
+        ]);
+        let archive = Archive::from_bytes(zip).unwrap();
+        let addresses = Addresses::from_archive(&archive).unwrap();
+
+        assert_eq!(
+            addresses.len(),
+            Addresses::from_utf8(SAMPLE_DATA.to_string()).unwrap().len() + 1
+        );
+        assert!(addresses.get(&AddressId::new(9999999)).is_some());
+
+        let zip = build_zip(&[("Delimitado/LOG_BAIRRO.TXT", b"", true)]);
+        let archive = Archive::from_bytes(zip).unwrap();
+        let err = Addresses::from_archive(&archive).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Archive);
+    }
 }
@@ -0,0 +1,528 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+//! Reading eDNE files straight from the zip archive.
+//!
+//! Correios ship the eDNE as a zip (e.g. eDNE_Basico.zip) with the LOG_*.TXT
+//! files in a `Delimitado/` folder. [`Archive`] reads the central directory
+//! of such a zip and extracts entries by file name, so the collections can
+//! be parsed without unzipping the archive first. Only stored and Deflate
+//! compressed entries are supported, which is all Correios use.
+
+use std::{fs::File, ops::Deref, path::Path};
+
+use memmap2::Mmap;
+
+use crate::parser::{
+    base::{EdneParser, ParseError},
+    encoding::Encoding,
+};
+
+/// Signature of the end of central directory record.
+const END_OF_CENTRAL_DIRECTORY: u32 = 0x0605_4b50;
+/// Signature of a central directory file header.
+const CENTRAL_DIRECTORY_HEADER: u32 = 0x0201_4b50;
+/// Signature of a local file header.
+const LOCAL_FILE_HEADER: u32 = 0x0403_4b50;
+/// Size of the end of central directory record without its comment.
+const END_OF_CENTRAL_DIRECTORY_LEN: usize = 22;
+/// Size of a central directory file header without its variable fields.
+const CENTRAL_DIRECTORY_HEADER_LEN: usize = 46;
+/// Size of a local file header without its variable fields.
+const LOCAL_FILE_HEADER_LEN: usize = 30;
+/// Compression method of stored (uncompressed) entries.
+const METHOD_STORED: u16 = 0;
+/// Compression method of Deflate compressed entries.
+const METHOD_DEFLATE: u16 = 8;
+/// Folder holding the '@' delimited files in the official archives.
+const DELIMITED_FOLDER: &str = "Delimitado";
+
+/// Bytes of an archive, either memory-mapped or owned.
+enum Data {
+    Mapped(Mmap),
+    Owned(Vec<u8>),
+}
+
+impl Deref for Data {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Self::Mapped(mmap) => mmap,
+            Self::Owned(bytes) => bytes,
+        }
+    }
+}
+
+/// A file stored in the archive.
+#[derive(Debug)]
+struct Entry {
+    path: String,
+    method: u16,
+    crc32: u32,
+    compressed_size: usize,
+    uncompressed_size: usize,
+    header_offset: usize,
+}
+
+impl Entry {
+    /// Returns the file name without its folders.
+    fn file_name(&self) -> &str {
+        self.path.rsplit(['/', '\\']).next().unwrap_or(&self.path)
+    }
+
+    /// Returns `true` if the entry is in the folder of delimited files.
+    fn is_delimited(&self) -> bool {
+        self.path
+            .split(['/', '\\'])
+            .any(|folder| folder.eq_ignore_ascii_case(DELIMITED_FOLDER))
+    }
+}
+
+/// A zip archive of eDNE files.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::path::Path;
+///
+/// use edne::parser::{Collection, archive::Archive, localities::Localities};
+///
+/// # fn main() -> Result<(), edne::ParseError> {
+/// let archive = Archive::open(Path::new("eDNE_Basico.zip"))?;
+/// let localities = Localities::from_archive(&archive)?;
+/// println!("Parsed {} localities", localities.len());
+/// # Ok(())
+/// # }
+/// ```
+pub struct Archive {
+    data: Data,
+    entries: Vec<Entry>,
+}
+
+impl Archive {
+    /// Opens a zip archive from a file.
+    ///
+    /// The file is memory-mapped; entries are extracted when they are read.
+    ///
+    /// The file must not be truncated or modified by another process while
+    /// the archive is alive. The mapping is read directly, and truncating a
+    /// mapped file makes the reads fault (SIGBUS on Unix), which is
+    /// undefined behaviour. Use [`Archive::from_bytes`] for files that may
+    /// change.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if the file cannot be read or is not a valid zip
+    /// archive.
+    pub fn open(path: &Path) -> Result<Self, ParseError> {
+        let io_error =
+            |source| ParseError::Io { path: Some(path.to_path_buf()), source };
+
+        let file = File::open(path).map_err(io_error)?;
+        // SAFETY: the file must not be truncated or modified while it is
+        // mapped, that is, while the archive is alive. Truncation makes reads
+        // of the mapping fault and modification breaks the immutability of
+        // the mapped bytes; both are undefined behaviour. This cannot be
+        // checked here, so it is part of the documented contract of `open`.
+        let mmap = unsafe { Mmap::map(&file) }.map_err(io_error)?;
+        Self::from_data(Data::Mapped(mmap))
+    }
+
+    /// Reads a zip archive from its bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::Archive` if the bytes are not a valid zip archive.
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, ParseError> {
+        Self::from_data(Data::Owned(bytes))
+    }
+
+    fn from_data(data: Data) -> Result<Self, ParseError> {
+        let entries = read_central_directory(&data)?;
+        Ok(Self { data, entries })
+    }
+
+    /// Returns the number of entries in the archive.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the archive has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over the full paths of all entries.
+    pub fn paths(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|entry| entry.path.as_str())
+    }
+
+    /// Returns the full path of the entry named `file_name`, if any.
+    ///
+    /// The name is matched without folders and ignoring ASCII case. When
+    /// several entries match, the one in the `Delimitado/` folder wins.
+    pub fn find(&self, file_name: &str) -> Option<&str> {
+        self.entry(file_name).map(|entry| entry.path.as_str())
+    }
+
+    /// Returns `true` if the archive has an entry named `file_name`.
+    pub fn contains(&self, file_name: &str) -> bool {
+        self.entry(file_name).is_some()
+    }
+
+    /// Extracts the entry named `file_name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::Archive` if there is no such entry, it uses an
+    /// unsupported compression method or its data is corrupt.
+    pub fn read(&self, file_name: &str) -> Result<Vec<u8>, ParseError> {
+        let entry = self.entry(file_name).ok_or_else(|| {
+            ParseError::Archive(format!("no entry named '{}'", file_name))
+        })?;
+        self.extract(entry)
+    }
+
+    /// Extracts the entry named `file_name` into a parser.
+    ///
+    /// The encoding of the entry is detected as with `Encoding::Auto`, so
+    /// re-encoded UTF-8 or Windows-1252 archives are read as well as the
+    /// official ISO-8859-1 ones. Errors of the parser report the path of
+    /// the entry as file name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if the entry cannot be extracted.
+    pub fn parser(&self, file_name: &str) -> Result<EdneParser, ParseError> {
+        let entry = self.entry(file_name).ok_or_else(|| {
+            ParseError::Archive(format!("no entry named '{}'", file_name))
+        })?;
+        let bytes = self.extract(entry)?;
+        let parser = EdneParser::from_bytes(&bytes, Encoding::Auto)?;
+        Ok(parser.with_file_name(&entry.path))
+    }
+
+    fn entry(&self, file_name: &str) -> Option<&Entry> {
+        let mut matches = self
+            .entries
+            .iter()
+            .filter(|entry| entry.file_name().eq_ignore_ascii_case(file_name));
+        let first = matches.next()?;
+        if first.is_delimited() {
+            return Some(first);
+        }
+        Some(matches.find(|entry| entry.is_delimited()).unwrap_or(first))
+    }
+
+    fn extract(&self, entry: &Entry) -> Result<Vec<u8>, ParseError> {
+        let error = |message: &str| {
+            ParseError::Archive(format!("{}: {}", entry.path, message))
+        };
+
+        let header = self
+            .data
+            .get(entry.header_offset..)
+            .filter(|header| header.len() >= LOCAL_FILE_HEADER_LEN)
+            .filter(|header| read_u32(header, 0) == LOCAL_FILE_HEADER)
+            .ok_or_else(|| error("invalid local file header"))?;
+        let start = entry.header_offset
+            + LOCAL_FILE_HEADER_LEN
+            + usize::from(read_u16(header, 26))
+            + usize::from(read_u16(header, 28));
+        let compressed = self
+            .data
+            .get(start..start + entry.compressed_size)
+            .ok_or_else(|| error("entry data is truncated"))?;
+
+        let bytes = match entry.method {
+            METHOD_STORED => compressed.to_vec(),
+            METHOD_DEFLATE => {
+                miniz_oxide::inflate::decompress_to_vec_with_limit(
+                    compressed,
+                    entry.uncompressed_size,
+                )
+                .map_err(|e| error(&format!("invalid Deflate data: {}", e)))?
+            }
+            method => {
+                return Err(error(&format!(
+                    "unsupported compression method {}",
+                    method
+                )));
+            }
+        };
+
+        if bytes.len() != entry.uncompressed_size {
+            return Err(error("entry size does not match"));
+        }
+        if crc32(&bytes) != entry.crc32 {
+            return Err(error("CRC-32 does not match"));
+        }
+        Ok(bytes)
+    }
+}
+
+/// Reads the entries listed in the central directory of `data`.
+fn read_central_directory(data: &[u8]) -> Result<Vec<Entry>, ParseError> {
+    let invalid = |message: &str| ParseError::Archive(message.to_string());
+
+    // The end record is followed by a comment of at most 64 KiB.
+    let last = data
+        .len()
+        .checked_sub(END_OF_CENTRAL_DIRECTORY_LEN)
+        .ok_or_else(|| invalid("end of central directory not found"))?;
+    let end = (last.saturating_sub(0xFFFF)..=last)
+        .rev()
+        .find(|&offset| read_u32(data, offset) == END_OF_CENTRAL_DIRECTORY)
+        .ok_or_else(|| invalid("end of central directory not found"))?;
+
+    let count = usize::from(read_u16(data, end + 10));
+    let mut offset = read_u32(data, end + 16) as usize;
+    if count == 0xFFFF || offset == 0xFFFF_FFFF {
+        return Err(invalid("ZIP64 archives are not supported"));
+    }
+
+    let mut entries = Vec::with_capacity(count);
+    for _ in 0..count {
+        let header = data
+            .get(offset..)
+            .filter(|header| header.len() >= CENTRAL_DIRECTORY_HEADER_LEN)
+            .filter(|header| read_u32(header, 0) == CENTRAL_DIRECTORY_HEADER)
+            .ok_or_else(|| invalid("invalid central directory header"))?;
+
+        let name_len = usize::from(read_u16(header, 28));
+        let extra_len = usize::from(read_u16(header, 30));
+        let comment_len = usize::from(read_u16(header, 32));
+        let name = header
+            .get(CENTRAL_DIRECTORY_HEADER_LEN..)
+            .and_then(|rest| rest.get(..name_len))
+            .ok_or_else(|| invalid("central directory is truncated"))?;
+
+        let entry = Entry {
+            // Names are ASCII in practice; decode them like the files.
+            path: name.iter().map(|&b| b as char).collect(),
+            method: read_u16(header, 10),
+            crc32: read_u32(header, 16),
+            compressed_size: read_u32(header, 20) as usize,
+            uncompressed_size: read_u32(header, 24) as usize,
+            header_offset: read_u32(header, 42) as usize,
+        };
+        // Folders are stored as empty entries ending with '/'.
+        if !entry.path.ends_with('/') {
+            entries.push(entry);
+        }
+
+        offset +=
+            CENTRAL_DIRECTORY_HEADER_LEN + name_len + extra_len + comment_len;
+    }
+
+    Ok(entries)
+}
+
+fn read_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([data[offset], data[offset + 1]])
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes([
+        data[offset],
+        data[offset + 1],
+        data[offset + 2],
+        data[offset + 3],
+    ])
+}
+
+/// Lookup table of the CRC-32 used by zip (reflected polynomial 0xEDB88320).
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc =
+                if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0u32, |crc, &byte| {
+        CRC32_TABLE[((crc ^ u32::from(byte)) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Builds a zip archive of `(path, content, deflate)` entries.
+    pub(crate) fn build_zip(files: &[(&str, &[u8], bool)]) -> Vec<u8> {
+        let mut zip = Vec::new();
+        let mut central = Vec::new();
+
+        for &(path, content, deflate) in files {
+            let (method, data) = if deflate {
+                (
+                    METHOD_DEFLATE,
+                    miniz_oxide::deflate::compress_to_vec(content, 6),
+                )
+            } else {
+                (METHOD_STORED, content.to_vec())
+            };
+            let offset = zip.len() as u32;
+            let mut common = Vec::new();
+            common.extend_from_slice(&20u16.to_le_bytes()); // version needed
+            common.extend_from_slice(&0u16.to_le_bytes()); // flags
+            common.extend_from_slice(&method.to_le_bytes());
+            common.extend_from_slice(&[0; 4]); // time and date
+            common.extend_from_slice(&crc32(content).to_le_bytes());
+            common.extend_from_slice(&(data.len() as u32).to_le_bytes());
+            common.extend_from_slice(&(content.len() as u32).to_le_bytes());
+            common.extend_from_slice(&(path.len() as u16).to_le_bytes());
+            common.extend_from_slice(&0u16.to_le_bytes()); // extra length
+
+            zip.extend_from_slice(&LOCAL_FILE_HEADER.to_le_bytes());
+            zip.extend_from_slice(&common);
+            zip.extend_from_slice(path.as_bytes());
+            zip.extend_from_slice(&data);
+
+            central.extend_from_slice(&CENTRAL_DIRECTORY_HEADER.to_le_bytes());
+            central.extend_from_slice(&20u16.to_le_bytes()); // version made by
+            central.extend_from_slice(&common);
+            central.extend_from_slice(&[0; 6]); // comment, disk, attributes
+            central.extend_from_slice(&[0; 4]); // external attributes
+            central.extend_from_slice(&offset.to_le_bytes());
+            central.extend_from_slice(path.as_bytes());
+        }
+
+        let central_offset = zip.len() as u32;
+        let count = files.len() as u16;
+        zip.extend_from_slice(&central);
+        zip.extend_from_slice(&END_OF_CENTRAL_DIRECTORY.to_le_bytes());
+        zip.extend_from_slice(&[0; 4]); // disk numbers
+        zip.extend_from_slice(&count.to_le_bytes());
+        zip.extend_from_slice(&count.to_le_bytes());
+        zip.extend_from_slice(&(central.len() as u32).to_le_bytes());
+        zip.extend_from_slice(&central_offset.to_le_bytes());
+        zip.extend_from_slice(&0u16.to_le_bytes()); // comment length
+        zip
+    }
+
+    #[test]
+    fn crc32_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn find_entries_ignoring_folder_and_case() {
+        let zip = build_zip(&[
+            ("eDNE_Basico/Delimitado/LOG_BAIRRO.TXT", b"a", true),
+            ("eDNE_Basico/Leia-me.txt", b"b", false),
+        ]);
+        let archive = Archive::from_bytes(zip).unwrap();
+
+        assert_eq!(archive.len(), 2);
+        assert_eq!(
+            archive.find("log_bairro.txt"),
+            Some("eDNE_Basico/Delimitado/LOG_BAIRRO.TXT")
+        );
+        assert!(archive.contains("LEIA-ME.TXT"));
+        assert!(!archive.contains("LOG_CPC.TXT"));
+    }
+
+    #[test]
+    fn find_prefers_delimited_folder() {
+        let zip = build_zip(&[
+            ("Fixo/LOG_CPC.TXT", b"fixed", false),
+            ("Delimitado/LOG_CPC.TXT", b"delimited", false),
+        ]);
+        let archive = Archive::from_bytes(zip).unwrap();
+
+        assert_eq!(
+            archive.find("LOG_CPC.TXT"),
+            Some("Delimitado/LOG_CPC.TXT")
+        );
+        assert_eq!(archive.read("LOG_CPC.TXT").unwrap(), b"delimited");
+    }
+
+    #[test]
+    fn read_stored_and_deflated_entries() {
+        let content = b"55400@AC@16@Conjunto Esperan\xe7a@Cj Esperan\xe7a\n";
+        let zip = build_zip(&[
+            ("Delimitado/LOG_BAIRRO.TXT", content, true),
+            ("Delimitado/LOG_CPC.TXT", content, false),
+        ]);
+        let archive = Archive::from_bytes(zip).unwrap();
+
+        assert_eq!(archive.read("LOG_BAIRRO.TXT").unwrap(), content);
+        assert_eq!(archive.read("LOG_CPC.TXT").unwrap(), content);
+
+        let parser = archive.parser("LOG_BAIRRO.TXT").unwrap();
+        assert!(parser.content().contains("Esperança"));
+        assert_eq!(parser.file_name(), Some("Delimitado/LOG_BAIRRO.TXT"));
+    }
+
+    #[test]
+    fn parser_detects_entry_encoding() {
+        let utf8 = "55400@AC@16@Conjunto Esperança@Cj Esperança\n";
+        let windows1252 = b"55400@AC@16@Conjunto \x93Esperan\xe7a\x94@\n";
+        let zip = build_zip(&[
+            ("Delimitado/LOG_BAIRRO.TXT", utf8.as_bytes(), true),
+            ("Delimitado/LOG_CPC.TXT", windows1252, false),
+        ]);
+        let archive = Archive::from_bytes(zip).unwrap();
+
+        let parser = archive.parser("LOG_BAIRRO.TXT").unwrap();
+        assert_eq!(parser.encoding(), Encoding::Utf8);
+        assert_eq!(parser.content(), utf8);
+
+        let parser = archive.parser("LOG_CPC.TXT").unwrap();
+        assert_eq!(parser.encoding(), Encoding::Windows1252);
+        assert!(parser.content().contains("\u{201c}Esperança\u{201d}"));
+    }
+
+    #[test]
+    fn read_missing_entry() {
+        let archive = Archive::from_bytes(build_zip(&[])).unwrap();
+        assert!(archive.is_empty());
+        assert!(matches!(
+            archive.read("LOG_CPC.TXT"),
+            Err(ParseError::Archive(_))
+        ));
+    }
+
+    #[test]
+    fn read_corrupt_entry() {
+        let mut zip = build_zip(&[("LOG_CPC.TXT", b"1285@AL", false)]);
+        let at = LOCAL_FILE_HEADER_LEN + "LOG_CPC.TXT".len();
+        zip[at] = b'9';
+        let archive = Archive::from_bytes(zip).unwrap();
+
+        let err = archive.read("LOG_CPC.TXT").unwrap_err();
+        assert!(err.to_string().contains("CRC-32"));
+    }
+
+    #[test]
+    fn open_invalid_archive() {
+        let result = Archive::from_bytes(b"not a zip file".to_vec());
+        assert!(matches!(result, Err(ParseError::Archive(_))));
+    }
+}
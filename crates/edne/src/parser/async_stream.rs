@@ -0,0 +1,214 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+//! Asynchronous parsing of eDNE files from tokio readers.
+//!
+//! [`AsyncRecordStream`] is the async counterpart of
+//! [`RecordStream`](crate::parser::RecordStream): it reads one line at a
+//! time from any `AsyncBufRead`, so files can be parsed as they are
+//! downloaded without blocking the runtime.
+
+use std::marker::PhantomData;
+
+use tokio::io::{AsyncBufRead, AsyncBufReadExt};
+
+use crate::parser::{
+    base::{Layout, ParseError},
+    encoding::Encoding,
+    record_stream::{LineState, Record},
+};
+
+/// Asynchronous stream over the records of an ISO-8859-1 encoded eDNE
+/// file.
+///
+/// Created by [`async_stream`] and [`async_stream_with_layout`]. Empty
+/// lines are skipped; line numbers in errors refer to the original file.
+pub struct AsyncRecordStream<T, R> {
+    reader: R,
+    state: LineState,
+    done: bool,
+    _record: PhantomData<fn() -> T>,
+}
+
+impl<T: Record, R: AsyncBufRead + Unpin> AsyncRecordStream<T, R> {
+    /// Creates a stream reading `reader` with the given layout.
+    pub fn new(reader: R, layout: Layout) -> Self {
+        Self {
+            reader,
+            state: LineState::new(layout),
+            done: false,
+            _record: PhantomData,
+        }
+    }
+
+    /// Sets the encoding of the input (ISO-8859-1 by default).
+    ///
+    /// With `Encoding::Auto` the encoding is detected line by line.
+    pub fn with_encoding(mut self, encoding: Encoding) -> Self {
+        self.state.set_encoding(encoding);
+        self
+    }
+
+    /// Sets the file name reported in error spans.
+    pub fn with_file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.state.set_file_name(file_name.into());
+        self
+    }
+
+    /// Returns the next record, or `None` at end of input.
+    ///
+    /// After an I/O error the stream ends.
+    pub async fn next_record(&mut self) -> Option<Result<T, ParseError>> {
+        if self.done {
+            return None;
+        }
+
+        match self.read_line().await {
+            Ok(true) => Some(self.state.parse()),
+            Ok(false) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+
+    /// Reads the next non-empty line into the buffer.
+    ///
+    /// Returns `Ok(false)` at end of input.
+    async fn read_line(&mut self) -> Result<bool, ParseError> {
+        loop {
+            self.state.buffer.clear();
+            let read =
+                self.reader.read_until(b'\n', &mut self.state.buffer).await?;
+            if read == 0 {
+                return Ok(false);
+            }
+            if self.state.end_line(read) {
+                return Ok(true);
+            }
+        }
+    }
+}
+
+/// Streams records of type `T` from an ISO-8859-1 encoded async reader
+/// using the '@' delimited layout.
+///
+/// # Example
+///
+/// ```rust
+/// use edne::{Neighborhood, parser::async_stream::async_stream};
+///
+/// # async fn run() -> Result<(), edne::ParseError> {
+/// let data = "55400@AC@16@Loteamento Jaguar@Lot Jaguar\n".as_bytes();
+/// let mut neighborhoods = async_stream::<Neighborhood, _>(data);
+/// while let Some(neighborhood) = neighborhoods.next_record().await {
+///     println!("{}", neighborhood?.name);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn async_stream<T: Record, R: AsyncBufRead + Unpin>(
+    reader: R,
+) -> AsyncRecordStream<T, R> {
+    AsyncRecordStream::new(reader, Layout::default())
+}
+
+/// Streams records of type `T` from an ISO-8859-1 encoded async reader
+/// using the given layout.
+pub fn async_stream_with_layout<T: Record, R: AsyncBufRead + Unpin>(
+    reader: R,
+    layout: Layout,
+) -> AsyncRecordStream<T, R> {
+    AsyncRecordStream::new(reader, layout)
+}
+
+/// Parses every record of an async reader in `encoding`, passing each one
+/// to `insert`.
+///
+/// With `Encoding::Auto` the encoding is detected line by line.
+///
+/// # Errors
+///
+/// Returns `ParseError` if reading fails or any line has invalid data.
+pub(crate) async fn parse_async_read<T, R, F>(
+    reader: R,
+    encoding: Encoding,
+    mut insert: F,
+) -> Result<(), ParseError>
+where
+    T: Record,
+    R: AsyncBufRead + Unpin,
+    F: FnMut(T),
+{
+    let mut records = async_stream::<T, _>(reader).with_encoding(encoding);
+    while let Some(record) = records.next_record().await {
+        insert(record?);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Neighborhood, NeighborhoodId};
+
+    #[tokio::test]
+    async fn async_stream_records() {
+        let data =
+            b"55400@AC@16@Conjunto Esperan\xe7a@Cj Esperan\xe7a\r\n\r\n\
+            55401@AC@16@Centro@\r\n";
+        let mut records = async_stream::<Neighborhood, _>(&data[..]);
+
+        let first = records.next_record().await.unwrap().unwrap();
+        assert_eq!(first.id, NeighborhoodId::new(55400));
+        assert_eq!(first.name, "Conjunto Esperança");
+        let second = records.next_record().await.unwrap().unwrap();
+        assert_eq!(second.name, "Centro");
+        assert!(records.next_record().await.is_none());
+        assert!(records.next_record().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn async_stream_error_span() {
+        let data = b"55400@AC@16@Jaguar@\n\n55401@XX@16@Centro@\n";
+        let mut records = async_stream::<Neighborhood, _>(&data[..])
+            .with_file_name("LOG_BAIRRO.TXT");
+
+        assert!(records.next_record().await.unwrap().is_ok());
+        let err = records.next_record().await.unwrap().unwrap_err();
+        let span = err.span().unwrap();
+        assert_eq!(span.file_name.as_deref(), Some("LOG_BAIRRO.TXT"));
+        assert_eq!(span.line, 3);
+        assert_eq!(span.byte_offset, 27);
+    }
+
+    #[tokio::test]
+    async fn async_stream_fixed_width() {
+        let data =
+            "00055400AC00000016Loteamento Jaguar        Lot Jaguar".as_bytes();
+        let layout = Layout::fixed_width([0, 8, 10, 18, 43]);
+        let mut records =
+            async_stream_with_layout::<Neighborhood, _>(data, layout)
+                .with_encoding(Encoding::Auto);
+
+        let record = records.next_record().await.unwrap().unwrap();
+        assert_eq!(record.name, "Loteamento Jaguar");
+    }
+}
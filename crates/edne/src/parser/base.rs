@@ -14,82 +14,470 @@
 // OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 //
 
-use std::{error::Error, fmt};
+use std::{error::Error, fmt, io, path::PathBuf};
+
+use crate::error::ValueError;
+use crate::parser::{
+    encoding::Encoding, record_stream::Record, span::SourceSpan,
+};
 
 /// Field separator used in eDNE files.
 pub const FIELD_SEPARATOR: char = '@';
 
 /// Errors that can occur during eDNE parsing.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Errors raised while parsing a line carry an optional [`SourceSpan`]
+/// pointing at the failing field. Format the error with `{:#}` to print the
+/// offending line with a caret under that field.
+///
+/// Use [`ParseError::kind`] or [`ParseError::code`] to tell errors apart
+/// without matching on their text.
+#[derive(Debug)]
+#[non_exhaustive]
 pub enum ParseError {
-    /// Failed to decode ISO-8859-1 bytes.
+    /// Failed to decode the input bytes in the expected encoding.
     EncodingError(String),
+    /// Failed to open or read the input.
+    Io {
+        /// File being read, if the input is a file.
+        path: Option<PathBuf>,
+        /// Underlying error, also returned by [`Error::source`].
+        source: io::Error,
+    },
     /// Expected a specific number of fields but got a different count.
-    FieldCount { expected: usize, got: usize, line_number: usize },
+    FieldCount {
+        expected: usize,
+        got: usize,
+        line_number: usize,
+        span: Option<Box<SourceSpan>>,
+    },
     /// Field is empty but was required.
-    EmptyField { field_name: &'static str, line_number: usize },
+    EmptyField {
+        field_name: &'static str,
+        line_number: usize,
+        span: Option<Box<SourceSpan>>,
+    },
     /// Failed to parse a numeric field.
     InvalidNumber {
         field_name: &'static str,
         value: String,
         line_number: usize,
+        span: Option<Box<SourceSpan>>,
     },
     /// Field value is invalid according to domain rules.
     InvalidValue {
         field_name: &'static str,
         value: String,
         reason: String,
+        /// Typed error of the model type that rejected the value, if any.
+        source: Option<ValueError>,
         line_number: usize,
+        span: Option<Box<SourceSpan>>,
     },
     /// Generic parsing error with context.
-    ParseFailed { message: String, line_number: usize },
+    ParseFailed {
+        message: String,
+        line_number: usize,
+        span: Option<Box<SourceSpan>>,
+    },
+    /// Lenient parsing gave up after more than `max` invalid lines.
+    TooManyErrors { max: usize, line_number: usize },
+    /// Failed to read an entry from a zip archive.
+    Archive(String),
+}
+
+/// Category of a [`ParseError`], for routing and counting errors.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Input bytes could not be decoded (`EDNE-E001`).
+    Encoding,
+    /// Input could not be read (`EDNE-E002`).
+    Io,
+    /// Wrong number of fields in a line (`EDNE-E003`).
+    FieldCount,
+    /// Required field is empty (`EDNE-E004`).
+    EmptyField,
+    /// Numeric field could not be parsed (`EDNE-E005`).
+    InvalidNumber,
+    /// Field value was rejected by a model type (`EDNE-E006`).
+    InvalidValue,
+    /// Any other parsing failure (`EDNE-E007`).
+    ParseFailed,
+    /// Lenient parsing gave up (`EDNE-E008`).
+    TooManyErrors,
+    /// Zip archive is invalid or lacks an entry (`EDNE-E009`).
+    Archive,
+}
+
+impl ErrorKind {
+    /// Returns the stable code of the error kind, e.g. `EDNE-E004`.
+    ///
+    /// Codes are never reused or renumbered.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Encoding => "EDNE-E001",
+            Self::Io => "EDNE-E002",
+            Self::FieldCount => "EDNE-E003",
+            Self::EmptyField => "EDNE-E004",
+            Self::InvalidNumber => "EDNE-E005",
+            Self::InvalidValue => "EDNE-E006",
+            Self::ParseFailed => "EDNE-E007",
+            Self::TooManyErrors => "EDNE-E008",
+            Self::Archive => "EDNE-E009",
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+impl ParseError {
+    /// Returns the category of the error.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::EncodingError(_) => ErrorKind::Encoding,
+            Self::Io { .. } => ErrorKind::Io,
+            Self::FieldCount { .. } => ErrorKind::FieldCount,
+            Self::EmptyField { .. } => ErrorKind::EmptyField,
+            Self::InvalidNumber { .. } => ErrorKind::InvalidNumber,
+            Self::InvalidValue { .. } => ErrorKind::InvalidValue,
+            Self::ParseFailed { .. } => ErrorKind::ParseFailed,
+            Self::TooManyErrors { .. } => ErrorKind::TooManyErrors,
+            Self::Archive(_) => ErrorKind::Archive,
+        }
+    }
+
+    /// Returns the stable code of the error, e.g. `EDNE-E004`.
+    pub fn code(&self) -> &'static str {
+        self.kind().code()
+    }
+
+    /// Returns the typed error of a rejected field value, if any.
+    pub fn value_error(&self) -> Option<&ValueError> {
+        match self {
+            Self::InvalidValue { source, .. } => source.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the field the error refers to, if any.
+    pub fn field_name(&self) -> Option<&'static str> {
+        match self {
+            Self::EmptyField { field_name, .. }
+            | Self::InvalidNumber { field_name, .. }
+            | Self::InvalidValue { field_name, .. } => Some(field_name),
+            _ => None,
+        }
+    }
+
+    /// Returns the line number the error refers to, if any.
+    pub fn line_number(&self) -> Option<usize> {
+        match self {
+            Self::FieldCount { line_number, .. }
+            | Self::EmptyField { line_number, .. }
+            | Self::InvalidNumber { line_number, .. }
+            | Self::InvalidValue { line_number, .. }
+            | Self::ParseFailed { line_number, .. }
+            | Self::TooManyErrors { line_number, .. } => Some(*line_number),
+            Self::EncodingError(_) | Self::Io { .. } | Self::Archive(_) => {
+                None
+            }
+        }
+    }
+
+    /// Returns the location of the error in the source file, if known.
+    pub fn span(&self) -> Option<&SourceSpan> {
+        match self {
+            Self::FieldCount { span, .. }
+            | Self::EmptyField { span, .. }
+            | Self::InvalidNumber { span, .. }
+            | Self::InvalidValue { span, .. }
+            | Self::ParseFailed { span, .. } => span.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Attaches a source location to the error.
+    ///
+    /// Errors that do not refer to a line are returned unchanged.
+    pub fn with_span(mut self, source_span: SourceSpan) -> Self {
+        match &mut self {
+            Self::FieldCount { span, .. }
+            | Self::EmptyField { span, .. }
+            | Self::InvalidNumber { span, .. }
+            | Self::InvalidValue { span, .. }
+            | Self::ParseFailed { span, .. } => {
+                *span = Some(Box::new(source_span));
+            }
+            _ => {}
+        }
+        self
+    }
 }
 
 impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "error[{}]: ", self.code())?;
+        }
         match self {
-            Self::EncodingError(msg) => write!(f, "encoding error: {}", msg),
-            Self::FieldCount { expected, got, line_number } => write!(
+            Self::EncodingError(msg) => write!(f, "encoding error: {}", msg)?,
+            Self::Io { path: Some(path), source } => {
+                write!(f, "I/O error: {}: {}", path.display(), source)?
+            }
+            Self::Io { path: None, source } => {
+                write!(f, "I/O error: {}", source)?
+            }
+            Self::FieldCount { expected, got, line_number, .. } => write!(
                 f,
                 "line {}: expected {} fields, got {}",
                 line_number, expected, got
-            ),
-            Self::EmptyField { field_name, line_number } => write!(
+            )?,
+            Self::EmptyField { field_name, line_number, .. } => write!(
                 f,
                 "line {}: field '{}' is empty",
                 line_number, field_name
-            ),
-            Self::InvalidNumber { field_name, value, line_number } => write!(
-                f,
-                "line {}: field '{}' has invalid number: '{}'",
-                line_number, field_name, value
-            ),
-            Self::InvalidValue { field_name, value, reason, line_number } => {
+            )?,
+            Self::InvalidNumber { field_name, value, line_number, .. } => {
                 write!(
                     f,
-                    "line {}: field '{}' has invalid value '{}': {}",
-                    line_number, field_name, value, reason
-                )
+                    "line {}: field '{}' has invalid number: '{}'",
+                    line_number, field_name, value
+                )?
+            }
+            Self::InvalidValue {
+                field_name,
+                value,
+                reason,
+                line_number,
+                ..
+            } => write!(
+                f,
+                "line {}: field '{}' has invalid value '{}': {}",
+                line_number, field_name, value, reason
+            )?,
+            Self::ParseFailed { message, line_number, .. } => {
+                write!(f, "line {}: {}", line_number, message)?
+            }
+            Self::TooManyErrors { max, line_number } => write!(
+                f,
+                "line {}: more than {} invalid lines, giving up",
+                line_number, max
+            )?,
+            Self::Archive(msg) => write!(f, "archive error: {}", msg)?,
+        }
+
+        // The alternate form prints the offending line, compiler-style.
+        if f.alternate()
+            && let Some(span) = self.span()
+        {
+            writeln!(f)?;
+            span.write_snippet(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<io::Error> for ParseError {
+    fn from(source: io::Error) -> Self {
+        Self::Io { path: None, source }
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => {
+                self.value_error().map(|e| e.inner() as &(dyn Error + 'static))
+            }
+        }
+    }
+}
+
+/// Column layout of an eDNE text file.
+///
+/// Current distributions separate fields with '@'. Older and "Master"
+/// distributions use fixed-width columns instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Layout {
+    /// Fields separated by a delimiter character.
+    Delimited(char),
+    /// Fixed-width columns given by the character offset where each column
+    /// starts. The last column extends to the end of the line.
+    FixedWidth(Vec<usize>),
+}
+
+impl Layout {
+    /// Creates a fixed-width layout from the start offset of each column.
+    pub fn fixed_width(offsets: impl Into<Vec<usize>>) -> Self {
+        Self::FixedWidth(offsets.into())
+    }
+
+    /// Creates a fixed-width layout from the width of each column.
+    pub fn from_widths(widths: &[usize]) -> Self {
+        let offsets = widths
+            .iter()
+            .scan(0, |start, width| {
+                let offset = *start;
+                *start += width;
+                Some(offset)
+            })
+            .collect();
+        Self::FixedWidth(offsets)
+    }
+
+    /// Splits a line into fields according to the layout.
+    ///
+    /// Fixed-width fields are trimmed of their padding; columns past the end
+    /// of a short line are returned as empty strings.
+    pub fn split<'a>(&self, line: &'a str) -> Vec<&'a str> {
+        match self {
+            Self::Delimited(separator) => line.split(*separator).collect(),
+            Self::FixedWidth(offsets) => {
+                let bounds: Vec<usize> = offsets
+                    .iter()
+                    .map(|&offset| char_to_byte(line, offset))
+                    .chain(std::iter::once(line.len()))
+                    .collect();
+                bounds
+                    .windows(2)
+                    .map(|w| {
+                        line.get(w[0]..w[1].max(w[0])).unwrap_or("").trim()
+                    })
+                    .collect()
             }
-            Self::ParseFailed { message, line_number } => {
-                write!(f, "line {}: {}", line_number, message)
+        }
+    }
+
+    /// Splits the last field off a line, returning the leading part and the
+    /// last field.
+    ///
+    /// Returns `None` if the line has a single field.
+    pub fn split_last<'a>(&self, line: &'a str) -> Option<(&'a str, &'a str)> {
+        match self {
+            Self::Delimited(separator) => line.rsplit_once(*separator),
+            Self::FixedWidth(offsets) => {
+                let (&last, rest) = offsets.split_last()?;
+                if rest.is_empty() {
+                    return None;
+                }
+                let at = char_to_byte(line, last);
+                Some((&line[..at], line[at..].trim()))
             }
         }
     }
+
+    /// Returns the character column and width of field `index` in `line`.
+    ///
+    /// Returns `None` if the line has no such field.
+    pub(crate) fn field_position(
+        &self,
+        line: &str,
+        index: usize,
+    ) -> Option<(usize, usize)> {
+        match self {
+            Self::Delimited(separator) => {
+                let mut column = 0;
+                for (idx, field) in line.split(*separator).enumerate() {
+                    let width = field.chars().count();
+                    if idx == index {
+                        return Some((column, width));
+                    }
+                    column += width + 1;
+                }
+                None
+            }
+            Self::FixedWidth(offsets) => {
+                let len = line.chars().count();
+                let start = *offsets.get(index)?;
+                if start >= len {
+                    return None;
+                }
+                let end = offsets.get(index + 1).map_or(len, |&e| e.min(len));
+                Some((start, end.saturating_sub(start)))
+            }
+        }
+    }
+
+    /// Returns the layout without its last column.
+    pub fn without_last(&self) -> Self {
+        match self {
+            Self::Delimited(separator) => Self::Delimited(*separator),
+            Self::FixedWidth(offsets) => {
+                let len = offsets.len().saturating_sub(1);
+                Self::FixedWidth(offsets[..len].to_vec())
+            }
+        }
+    }
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Self::Delimited(FIELD_SEPARATOR)
+    }
+}
+
+/// Converts a character offset into a byte offset of `line`, clamped to its
+/// length.
+fn char_to_byte(line: &str, offset: usize) -> usize {
+    line.char_indices().nth(offset).map_or(line.len(), |(idx, _)| idx)
+}
+
+/// A non-empty line of an eDNE file.
+pub(crate) struct Line<'a> {
+    /// 1-based line number.
+    pub(crate) number: usize,
+    /// Byte offset of the line in the source encoding.
+    pub(crate) offset: usize,
+    /// Line content, without the line ending.
+    pub(crate) text: &'a str,
 }
 
-impl Error for ParseError {}
+/// Splits `text` into its non-empty lines, numbered from `first_number`.
+///
+/// Offsets start at `first_offset` and are kept as a running sum, so the
+/// whole text is measured once.
+pub(crate) fn split_lines(
+    text: &str,
+    first_number: usize,
+    first_offset: usize,
+    encoding: Encoding,
+) -> impl Iterator<Item = Line<'_>> {
+    let mut offset = first_offset;
+    text.split_inclusive('\n').zip(first_number..).filter_map(
+        move |(raw, number)| {
+            let line_offset = offset;
+            offset += encoding.encoded_len(raw);
+
+            let text = raw
+                .strip_suffix('\n')
+                .map_or(raw, |line| line.strip_suffix('\r').unwrap_or(line));
+            (!text.trim().is_empty()).then_some(Line {
+                number,
+                offset: line_offset,
+                text,
+            })
+        },
+    )
+}
 
 /// Generic parser for eDNE text files.
 ///
 /// This parser handles the common structure of eDNE files:
 /// - ISO-8859-1 encoding
 /// - One record per line
-/// - Fields separated by '@'
+/// - Fields separated by '@', or fixed-width columns (see [`Layout`])
 /// - Optional trailing separator
 pub struct EdneParser {
     content: String,
-    separator: char,
+    layout: Layout,
+    encoding: Encoding,
+    file_name: Option<String>,
 }
 
 impl EdneParser {
@@ -104,19 +492,73 @@ impl EdneParser {
     /// Returns `ParseError::EncodingError` if bytes cannot be decoded.
     pub fn from_iso8859_1(bytes: &[u8]) -> Result<Self, ParseError> {
         let content = Self::decode_iso8859_1(bytes)?;
-        Ok(Self { content, separator: FIELD_SEPARATOR })
+        Ok(Self::with_encoding(content, Encoding::Latin1))
+    }
+
+    /// Creates a new parser from bytes in the given encoding.
+    ///
+    /// Use `Encoding::Auto` for files of unknown origin, such as mirrors that
+    /// re-encode the official ISO-8859-1 distribution.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::EncodingError` if bytes cannot be decoded.
+    pub fn from_bytes(
+        bytes: &[u8],
+        encoding: Encoding,
+    ) -> Result<Self, ParseError> {
+        let encoding = match encoding {
+            Encoding::Auto => Encoding::detect(bytes),
+            other => other,
+        };
+        let content = encoding.decode(bytes)?;
+        Ok(Self::with_encoding(content, encoding))
     }
 
     /// Creates a new parser from UTF-8 string (for testing).
     pub fn from_utf8(content: String) -> Self {
-        Self { content, separator: FIELD_SEPARATOR }
+        Self::with_encoding(content, Encoding::Utf8)
+    }
+
+    /// Creates a parser over `content` decoded from `encoding`.
+    fn with_encoding(content: String, encoding: Encoding) -> Self {
+        Self { content, layout: Layout::default(), encoding, file_name: None }
+    }
+
+    /// Sets the column layout used to split lines.
+    pub fn with_layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Returns the column layout used to split lines.
+    pub fn layout(&self) -> &Layout {
+        &self.layout
+    }
+
+    /// Sets the file name reported in error spans.
+    pub fn with_file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    /// Returns the file name reported in error spans, if set.
+    pub fn file_name(&self) -> Option<&str> {
+        self.file_name.as_deref()
+    }
+
+    /// Returns the encoding the content was decoded from.
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
     }
 
     /// Decodes ISO-8859-1 bytes to UTF-8 string.
     ///
     /// ISO-8859-1 is a single-byte encoding where each byte maps directly
     /// to a Unicode code point in the range 0x00-0xFF.
-    fn decode_iso8859_1(bytes: &[u8]) -> Result<String, ParseError> {
+    pub(crate) fn decode_iso8859_1(
+        bytes: &[u8],
+    ) -> Result<String, ParseError> {
         let mut result = String::with_capacity(bytes.len());
         for &byte in bytes {
             result.push(byte as char);
@@ -144,7 +586,7 @@ impl EdneParser {
     /// A vector of field strings. Empty fields are preserved as empty strings.
     /// Trailing separators are handled correctly (they create an empty last field).
     pub fn parse_line<'a>(&self, line: &'a str) -> Vec<&'a str> {
-        line.split(self.separator).collect()
+        self.layout.split(line)
     }
 
     /// Parses a line and validates the field count.
@@ -170,6 +612,7 @@ impl EdneParser {
                 expected: expected_count,
                 got: fields.len(),
                 line_number,
+                span: None,
             });
         }
         Ok(fields)
@@ -186,7 +629,11 @@ impl EdneParser {
         line_number: usize,
     ) -> Result<String, ParseError> {
         if field.trim().is_empty() {
-            return Err(ParseError::EmptyField { field_name, line_number });
+            return Err(ParseError::EmptyField {
+                field_name,
+                line_number,
+                span: None,
+            });
         }
         Ok(field.to_string())
     }
@@ -198,6 +645,34 @@ impl EdneParser {
         if field.trim().is_empty() { None } else { Some(field.to_string()) }
     }
 
+    /// Extracts a required field as a slice of the line, without allocating.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::EmptyField` if the field is empty.
+    pub fn required_str<'a>(
+        field: &'a str,
+        field_name: &'static str,
+        line_number: usize,
+    ) -> Result<&'a str, ParseError> {
+        if field.trim().is_empty() {
+            return Err(ParseError::EmptyField {
+                field_name,
+                line_number,
+                span: None,
+            });
+        }
+        Ok(field)
+    }
+
+    /// Extracts an optional field as a slice of the line, without
+    /// allocating.
+    ///
+    /// Returns `None` if the field is empty, `Some(&str)` otherwise.
+    pub fn optional_str(field: &str) -> Option<&str> {
+        if field.trim().is_empty() { None } else { Some(field) }
+    }
+
     /// Parses a required numeric field.
     ///
     /// # Errors
@@ -215,6 +690,7 @@ impl EdneParser {
             field_name,
             value: field.to_string(),
             line_number,
+            span: None,
         })
     }
 
@@ -237,6 +713,78 @@ impl EdneParser {
         Self::parse_number(field, field_name, line_number).map(Some)
     }
 
+    /// Returns an iterator over non-empty lines with their byte offsets.
+    pub(crate) fn located_lines(&self) -> impl Iterator<Item = Line<'_>> {
+        split_lines(&self.content, 1, 0, self.encoding)
+    }
+
+    /// Parses a line of this parser's content into a record of type `T`.
+    ///
+    /// Unlike `T::parse_line`, errors carry a [`SourceSpan`] pointing at the
+    /// failing field. Byte offsets are relative to the start of the content
+    /// when `line` is a slice of it, and to the start of the line otherwise.
+    ///
+    /// Locating an error measures the content before `line`, so parse whole
+    /// files with the [`Collection`](crate::parser::Collection)
+    /// constructors, which track the offsets as they go.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if the line has invalid data.
+    pub fn parse_record<T: Record>(
+        &self,
+        line: &str,
+        line_number: usize,
+    ) -> Result<T, ParseError> {
+        T::parse_line(self, line, line_number).map_err(|e| {
+            let start = self.content.as_ptr() as usize;
+            let offset = (line.as_ptr() as usize)
+                .checked_sub(start)
+                .filter(|&offset| offset <= self.content.len())
+                .map_or(0, |offset| {
+                    self.encoding.encoded_len(&self.content[..offset])
+                });
+            self.locate(e, T::FIELDS, line, offset)
+        })
+    }
+
+    /// Parses a line returned by [`EdneParser::located_lines`] into a record
+    /// of type `T`, like [`EdneParser::parse_record`].
+    pub(crate) fn parse_located<T: Record>(
+        &self,
+        line: &Line<'_>,
+    ) -> Result<T, ParseError> {
+        T::parse_line(self, line.text, line.number)
+            .map_err(|e| self.locate(e, T::FIELDS, line.text, line.offset))
+    }
+
+    /// Attaches the location of `line`, which starts at byte `line_offset`,
+    /// to `error`.
+    ///
+    /// `fields` names the columns of the line so that the failing field can
+    /// be found.
+    pub(crate) fn locate(
+        &self,
+        error: ParseError,
+        fields: &[&str],
+        line: &str,
+        line_offset: usize,
+    ) -> ParseError {
+        let span = SourceSpan::locate(
+            &error,
+            fields,
+            &self.layout,
+            self.encoding,
+            line,
+            line_offset,
+            self.file_name.as_deref(),
+        );
+        match span {
+            Some(span) => error.with_span(span),
+            None => error,
+        }
+    }
+
     /// Returns the full content as a string slice.
     pub fn content(&self) -> &str {
         &self.content
@@ -246,6 +794,11 @@ impl EdneParser {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        error::UfParseError,
+        models::Neighborhood,
+        parser::{collection::Collection, neighborhoods::Neighborhoods},
+    };
 
     #[test]
     fn decode_iso8859_1_basic() {
@@ -297,7 +850,7 @@ mod tests {
         let result = parser.parse_line_checked("a@b", 3, 1);
         assert!(result.is_err());
         match result.unwrap_err() {
-            ParseError::FieldCount { expected, got, line_number } => {
+            ParseError::FieldCount { expected, got, line_number, .. } => {
                 assert_eq!(expected, 3);
                 assert_eq!(got, 2);
                 assert_eq!(line_number, 1);
@@ -318,7 +871,7 @@ mod tests {
         let result = EdneParser::required_field("  ", "test_field", 1);
         assert!(result.is_err());
         match result.unwrap_err() {
-            ParseError::EmptyField { field_name, line_number } => {
+            ParseError::EmptyField { field_name, line_number, .. } => {
                 assert_eq!(field_name, "test_field");
                 assert_eq!(line_number, 1);
             }
@@ -376,4 +929,187 @@ mod tests {
         assert_eq!(lines[1], (3, "line2"));
         assert_eq!(lines[2], (5, "line3"));
     }
+
+    #[test]
+    fn located_lines_track_byte_offsets() {
+        let parser =
+            EdneParser::from_iso8859_1(b"a@1\r\n\nb@\xe7\n  \nc").unwrap();
+        let lines: Vec<_> = parser
+            .located_lines()
+            .map(|line| (line.number, line.offset, line.text))
+            .collect();
+
+        assert_eq!(lines, [(1, 0, "a@1"), (3, 6, "b@ç"), (5, 13, "c")]);
+        let numbered: Vec<_> =
+            lines.iter().map(|&(number, _, text)| (number, text)).collect();
+        assert_eq!(numbered, parser.lines().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn layout_from_widths() {
+        let layout = Layout::from_widths(&[3, 2, 5]);
+        assert_eq!(layout, Layout::FixedWidth(vec![0, 3, 5]));
+    }
+
+    #[test]
+    fn parse_line_fixed_width() {
+        let parser = EdneParser::from_utf8(String::new())
+            .with_layout(Layout::fixed_width([0, 5, 7]));
+        let fields = parser.parse_line("  16 ACRio Branco  ");
+        assert_eq!(fields, vec!["16", "AC", "Rio Branco"]);
+    }
+
+    #[test]
+    fn parse_line_fixed_width_with_accents() {
+        let parser = EdneParser::from_utf8(String::new())
+            .with_layout(Layout::fixed_width([0, 4, 7]));
+        let fields = parser.parse_line("São SP Paulo");
+        assert_eq!(fields, vec!["São", "SP", "Paulo"]);
+    }
+
+    #[test]
+    fn parse_line_fixed_width_short_line() {
+        let parser = EdneParser::from_utf8(String::new())
+            .with_layout(Layout::fixed_width([0, 5, 7]));
+        let fields = parser.parse_line("   16");
+        assert_eq!(fields, vec!["16", "", ""]);
+    }
+
+    #[test]
+    fn layout_split_last() {
+        let delimited = Layout::default();
+        assert_eq!(delimited.split_last("a@b@INS"), Some(("a@b", "INS")));
+        assert_eq!(delimited.split_last("a"), None);
+
+        let fixed = Layout::fixed_width([0, 3, 6]);
+        assert_eq!(fixed.split_last(" 16 ACINS"), Some((" 16 AC", "INS")));
+        assert_eq!(fixed.without_last(), Layout::fixed_width([0, 3]));
+    }
+
+    #[test]
+    fn required_str_borrows_field() {
+        let line = String::from("value@other");
+        let result = EdneParser::required_str(&line[..5], "test_field", 1);
+        assert_eq!(result.unwrap(), "value");
+        assert!(EdneParser::required_str(" ", "test_field", 1).is_err());
+    }
+
+    #[test]
+    fn optional_str_empty() {
+        assert_eq!(EdneParser::optional_str("value"), Some("value"));
+        assert_eq!(EdneParser::optional_str("  "), None);
+    }
+
+    #[test]
+    fn from_bytes_with_encoding() {
+        let parser =
+            EdneParser::from_bytes("São Paulo".as_bytes(), Encoding::Auto)
+                .unwrap();
+        assert_eq!(parser.content(), "São Paulo");
+
+        let result = EdneParser::from_bytes(b"S\xe3o", Encoding::Utf8);
+        assert!(matches!(result, Err(ParseError::EncodingError(_))));
+    }
+
+    #[test]
+    fn field_position_in_line() {
+        let delimited = Layout::default();
+        assert_eq!(delimited.field_position("1@São@X", 1), Some((2, 3)));
+        assert_eq!(delimited.field_position("1@São@X", 2), Some((6, 1)));
+        assert_eq!(delimited.field_position("1@São@X", 3), None);
+
+        let fixed = Layout::fixed_width([0, 3, 6]);
+        assert_eq!(fixed.field_position(" 16 ACINS", 1), Some((3, 3)));
+        assert_eq!(fixed.field_position(" 16 AC", 2), None);
+    }
+
+    #[test]
+    fn parse_record_error_span() {
+        let latin1 =
+            b"55400@AC@16@Esperan\xe7a@Esp\n55401@XX@16@Centro@Centro";
+        let parser = EdneParser::from_iso8859_1(latin1)
+            .unwrap()
+            .with_file_name("LOG_BAIRRO.TXT");
+        let err = Neighborhoods::from_parser(&parser).unwrap_err();
+        let span = err.span().unwrap();
+
+        assert_eq!(span.file_name.as_deref(), Some("LOG_BAIRRO.TXT"));
+        assert_eq!(span.byte_offset, 32);
+        assert_eq!(span.line, 2);
+        assert_eq!(span.column, 7);
+        assert_eq!(span.field_index, Some(1));
+        assert_eq!(span.width, 2);
+        assert_eq!(span.source_line, "55401@XX@16@Centro@Centro");
+
+        // The same text takes one more byte in UTF-8.
+        let parser = EdneParser::from_utf8(parser.content().to_string());
+        let err = Neighborhoods::from_parser(&parser).unwrap_err();
+        assert_eq!(err.span().unwrap().byte_offset, 33);
+    }
+
+    #[test]
+    fn parse_record_field_count_span() {
+        let parser = EdneParser::from_utf8("55400@AC@16".to_string());
+        let err = parser.parse_record::<Neighborhood>("55400@AC@16", 1);
+        let span = err.unwrap_err().span().cloned().unwrap();
+
+        assert_eq!(span.column, 1);
+        assert_eq!(span.field_index, None);
+        assert_eq!(span.width, 11);
+    }
+
+    #[test]
+    fn display_alternate_shows_caret() {
+        let parser = EdneParser::from_utf8(
+            "55400@AC@16@Jaguar@J\n55401@XX@16@Centro@Centro".to_string(),
+        );
+        let err = Neighborhoods::from_parser(&parser).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "line 2: field 'UFE_SG' has invalid value 'XX': invalid UF code: XX"
+        );
+        let expected = "\
+error[EDNE-E006]: line 2: field 'UFE_SG' has invalid value 'XX': invalid UF code: XX
+  --> <input>:2:7 (byte 27)
+  |
+2 | 55401@XX@16@Centro@Centro
+  |       ^^";
+        assert_eq!(format!("{:#}", err), expected);
+    }
+
+    #[test]
+    fn display_alternate_without_span() {
+        let err = EdneParser::required_field("", "BAI_NO", 3).unwrap_err();
+        assert_eq!(
+            format!("{:#}", err),
+            "error[EDNE-E004]: line 3: field 'BAI_NO' is empty"
+        );
+    }
+
+    #[test]
+    fn error_kind_and_code() {
+        let err = EdneParser::required_field("", "BAI_NO", 1).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::EmptyField);
+        assert_eq!(err.code(), "EDNE-E004");
+
+        let err = EdneParser::parse_number::<u32>("x", "LOC_NU", 1);
+        assert_eq!(err.unwrap_err().code(), "EDNE-E005");
+        assert_eq!(ErrorKind::Io.to_string(), "EDNE-E002");
+    }
+
+    #[test]
+    fn invalid_value_keeps_typed_source() {
+        let parser =
+            EdneParser::from_utf8("55400@XX@16@Centro@Centro".to_string());
+        let err = Neighborhoods::from_parser(&parser).unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidValue);
+        assert_eq!(
+            err.value_error(),
+            Some(&ValueError::Uf(UfParseError::InvalidCode("XX".to_string())))
+        );
+        let source = err.source().unwrap();
+        assert!(source.downcast_ref::<UfParseError>().is_some());
+    }
 }
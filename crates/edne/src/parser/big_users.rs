@@ -19,9 +19,14 @@ use std::{collections::HashMap, str::FromStr};
 use crate::{
     models::{
         LocalityId, NeighborhoodId, Uf,
-        big_user::{BigUser, BigUserId, StreetId},
+        big_user::{BigUser, BigUserId, BigUserRef, StreetId},
+    },
+    parser::{
+        base::{EdneParser, ParseError},
+        collection::{Collection, DeltaCollection},
+        helpers,
+        record_stream::{Record, RecordRef},
     },
-    parser::base::{EdneParser, ParseError},
 };
 
 const BIG_USER_FIELD_COUNT: usize = 9;
@@ -54,36 +59,95 @@ impl BigUsers {
         self.0.insert(big_user.id, big_user)
     }
 
+    /// Removes a big user by ID, returning it if it was present.
+    pub fn remove(&mut self, id: &BigUserId) -> Option<BigUser> {
+        self.0.remove(id)
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = (&BigUserId, &BigUser)> {
         self.0.iter()
     }
+}
 
-    pub fn from_iso8859_1(bytes: &[u8]) -> Result<Self, ParseError> {
-        let parser = EdneParser::from_iso8859_1(bytes)?;
-        Self::parse_with_parser(&parser)
+impl Default for BigUsers {
+    fn default() -> Self {
+        Self::new()
     }
+}
+
+impl Collection for BigUsers {
+    type Record = BigUser;
+    const ARCHIVE_ENTRY: &'static str = "LOG_GRANDE_USUARIO.TXT";
 
-    pub fn from_utf8(content: String) -> Result<Self, ParseError> {
-        let parser = EdneParser::from_utf8(content);
-        Self::parse_with_parser(&parser)
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity(capacity)
     }
+}
+
+impl DeltaCollection for BigUsers {
+    type Key = BigUserId;
+    const OPERATION_FIELD: &'static str = "GRU_OPERACAO";
 
-    fn parse_with_parser(parser: &EdneParser) -> Result<Self, ParseError> {
-        let lines: Vec<_> = parser.lines().collect();
-        let mut big_users = Self::with_capacity(lines.len());
+    fn key(record: &BigUser) -> BigUserId {
+        record.id
+    }
 
-        for (line_number, line) in lines {
-            let big_user = parse_big_user_line(parser, line, line_number)?;
-            big_users.insert(big_user);
+    fn insert(&mut self, record: BigUser) -> Option<BigUser> {
+        self.0.insert(record.id, record)
+    }
+
+    fn remove(&mut self, key: &BigUserId) -> Option<BigUser> {
+        self.0.remove(key)
+    }
+}
+
+impl Extend<BigUser> for BigUsers {
+    fn extend<I: IntoIterator<Item = BigUser>>(&mut self, iter: I) {
+        for record in iter {
+            self.insert(record);
         }
+    }
+}
 
-        Ok(big_users)
+impl FromIterator<BigUser> for BigUsers {
+    fn from_iter<I: IntoIterator<Item = BigUser>>(iter: I) -> Self {
+        let mut records = Self::new();
+        records.extend(iter);
+        records
     }
 }
 
-impl Default for BigUsers {
-    fn default() -> Self {
-        Self::new()
+impl Record for BigUser {
+    const FIELDS: &'static [&'static str] = &[
+        "GRU_NU",
+        "UFE_SG",
+        "LOC_NU",
+        "BAI_NU",
+        "LOG_NU",
+        "GRU_NO",
+        "GRU_ENDERECO",
+        "CEP",
+        "GRU_NO_ABREV",
+    ];
+
+    fn parse_line(
+        parser: &EdneParser,
+        line: &str,
+        line_number: usize,
+    ) -> Result<Self, ParseError> {
+        parse_big_user_line(parser, line, line_number)
+    }
+}
+
+impl<'a> RecordRef<'a> for BigUserRef<'a> {
+    type Owned = BigUser;
+
+    fn parse_line(
+        parser: &EdneParser,
+        line: &'a str,
+        line_number: usize,
+    ) -> Result<Self, ParseError> {
+        parse_big_user_ref_line(parser, line, line_number)
     }
 }
 
@@ -92,71 +156,90 @@ fn parse_big_user_line(
     line: &str,
     line_number: usize,
 ) -> Result<BigUser, ParseError> {
+    parse_big_user_ref_line(parser, line, line_number).map(|r| r.to_owned())
+}
+
+/// Parses a single line into a borrowed `BigUserRef`, without allocating its
+/// text fields. The field order is the same as [`parse_big_user_line`].
+fn parse_big_user_ref_line<'a>(
+    parser: &EdneParser,
+    line: &'a str,
+    line_number: usize,
+) -> Result<BigUserRef<'a>, ParseError> {
     let fields =
         parser.parse_line_checked(line, BIG_USER_FIELD_COUNT, line_number)?;
 
-    let id_str = EdneParser::required_field(fields[0], "GRU_NU", line_number)?;
-    let id = BigUserId::from_str(&id_str).map_err(|e| {
-        ParseError::InvalidValue {
+    let id_str = EdneParser::required_str(fields[0], "GRU_NU", line_number)?;
+    let id =
+        BigUserId::from_str(id_str).map_err(|e| ParseError::InvalidValue {
             field_name: "GRU_NU",
-            value: id_str,
+            value: id_str.to_string(),
             reason: e.to_string(),
+            source: Some(e.into()),
             line_number,
-        }
-    })?;
+            span: None,
+        })?;
 
-    let uf_str = EdneParser::required_field(fields[1], "UFE_SG", line_number)?;
-    let uf = Uf::from_str(&uf_str).map_err(|e| ParseError::InvalidValue {
+    let uf_str = EdneParser::required_str(fields[1], "UFE_SG", line_number)?;
+    let uf = Uf::from_str(uf_str).map_err(|e| ParseError::InvalidValue {
         field_name: "UFE_SG",
-        value: uf_str,
+        value: uf_str.to_string(),
         reason: e.to_string(),
+        source: Some(e.into()),
         line_number,
+        span: None,
     })?;
 
     let loc_id_str =
-        EdneParser::required_field(fields[2], "LOC_NU", line_number)?;
-    let locality_id = LocalityId::from_str(&loc_id_str).map_err(|e| {
+        EdneParser::required_str(fields[2], "LOC_NU", line_number)?;
+    let locality_id = LocalityId::from_str(loc_id_str).map_err(|e| {
         ParseError::InvalidValue {
             field_name: "LOC_NU",
-            value: loc_id_str,
+            value: loc_id_str.to_string(),
             reason: e.to_string(),
+            source: Some(e.into()),
             line_number,
+            span: None,
         }
     })?;
 
     let bai_id_str =
-        EdneParser::required_field(fields[3], "BAI_NU", line_number)?;
+        EdneParser::required_str(fields[3], "BAI_NU", line_number)?;
     let neighborhood_id =
-        NeighborhoodId::from_str(&bai_id_str).map_err(|e| {
+        NeighborhoodId::from_str(bai_id_str).map_err(|e| {
             ParseError::InvalidValue {
                 field_name: "BAI_NU",
-                value: bai_id_str,
+                value: bai_id_str.to_string(),
                 reason: e.to_string(),
+                source: Some(e.into()),
                 line_number,
+                span: None,
             }
         })?;
 
     let street_id =
-        if let Some(log_id_str) = EdneParser::optional_field(fields[4]) {
-            Some(StreetId::from_str(&log_id_str).map_err(|e| {
+        if let Some(log_id_str) = EdneParser::optional_str(fields[4]) {
+            Some(StreetId::from_str(log_id_str).map_err(|e| {
                 ParseError::InvalidValue {
                     field_name: "LOG_NU",
-                    value: log_id_str,
+                    value: log_id_str.to_string(),
                     reason: e.to_string(),
+                    source: Some(e.into()),
                     line_number,
+                    span: None,
                 }
             })?)
         } else {
             None
         };
 
-    let name = EdneParser::required_field(fields[5], "GRU_NO", line_number)?;
+    let name = EdneParser::required_str(fields[5], "GRU_NO", line_number)?;
     let address =
-        EdneParser::required_field(fields[6], "GRU_ENDERECO", line_number)?;
-    let cep = EdneParser::required_field(fields[7], "CEP", line_number)?;
-    let abbreviated_name = EdneParser::optional_field(fields[8]);
+        EdneParser::required_str(fields[6], "GRU_ENDERECO", line_number)?;
+    let cep = helpers::required_cep(fields[7], "CEP", line_number)?;
+    let abbreviated_name = EdneParser::optional_str(fields[8]);
 
-    Ok(BigUser {
+    Ok(BigUserRef {
         id,
         uf,
         locality_id,
@@ -172,6 +255,7 @@ fn parse_big_user_line(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::Cep;
 
     const SAMPLE_DATA: &str = "\
 41739@AC@16@49922@949512@PCL Ponto de Coleta Mercantil Júnior Clique e Retire@Rua Valdomiro Lopes, 2398 Clique e Retire Correios@69919959@PCL P C M J C Retire
@@ -209,7 +293,7 @@ mod tests {
         assert_eq!(user.street_id, Some(StreetId::new(949512)));
         assert!(user.name.contains("PCL"));
         assert!(user.address.contains("Rua Valdomiro Lopes"));
-        assert_eq!(user.cep, "69919959");
+        assert_eq!(user.cep, Cep::new(69919959));
     }
 
     #[test]
@@ -228,4 +312,43 @@ mod tests {
         let result = BigUsers::from_utf8(invalid.to_string());
         assert!(result.is_err());
     }
+
+    const DELTA_DATA: &str = "\
+41739@AC@16@49922@949512@Nome Novo@Rua Valdomiro Lopes, 2398 Clique e Retire Correios@69919959@PCL P C M J C Retire@UPD
+34344@AC@16@55439@948258@Residencial Ecoville@Rodovia BR-364, 2081@69915900@Res Ecoville@DEL
+999999@AC@16@49922@949512@PCL Ponto de Coleta Mercantil Júnior Clique e Retire@Rua Valdomiro Lopes, 2398 Clique e Retire Correios@69919959@PCL P C M J C Retire@INS";
+
+    #[test]
+    fn apply_delta_to_base() {
+        let mut big_users =
+            BigUsers::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        let before = big_users.len();
+        let deltas =
+            BigUsers::deltas_from_utf8(DELTA_DATA.to_string()).unwrap();
+        assert_eq!(deltas.len(), 3);
+
+        for delta in deltas {
+            big_users.apply_delta(delta);
+        }
+
+        assert_eq!(big_users.len(), before);
+        assert_eq!(
+            big_users.get(&BigUserId::new(41739)).unwrap().name,
+            "Nome Novo"
+        );
+        assert!(big_users.get(&BigUserId::new(34344)).is_none());
+        assert!(big_users.get(&BigUserId::new(999999)).is_some());
+    }
+
+    #[test]
+    fn parse_delta_invalid_operation() {
+        let invalid = "34344@AC@16@55439@948258@Residencial Ecoville@Rodovia BR-364, 2081@69915900@Res Ecoville@XXX";
+        let result = BigUsers::deltas_from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::InvalidValue { field_name, .. } => {
+                assert_eq!(field_name, "GRU_OPERACAO");
+            }
+            _ => panic!("Expected InvalidValue error"),
+        }
+    }
 }
@@ -0,0 +1,496 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+//! Constructors shared by every record collection.
+//!
+//! [`Collection`] builds a collection such as
+//! [`Localities`](crate::parser::localities::Localities) from bytes, files,
+//! readers or an eDNE archive. Each collection only names its record type
+//! and archive entry; the parsing itself is implemented once here.
+//!
+//! # Example
+//!
+//! ```rust
+//! use edne::parser::{Collection, localities::Localities};
+//!
+//! # fn main() -> Result<(), edne::ParseError> {
+//! let data = "16@AC@Rio Branco@@1@M@@Rio Branco@1200401";
+//! let localities = Localities::from_utf8(data.to_string())?;
+//! assert_eq!(localities.len(), 1);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{io::Read, iter, path::Path};
+
+use crate::models::delta::{Delta, DeltaOperation};
+use crate::parser::{
+    archive::Archive,
+    base::{EdneParser, ParseError},
+    delta,
+    encoding::Encoding,
+    lenient::{self, LenientParse, ParseOptions},
+    mapped,
+    record_stream::{self, Record},
+};
+
+#[cfg(feature = "async")]
+use crate::parser::async_stream;
+#[cfg(feature = "parallel")]
+use crate::parser::parallel;
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use tokio::io::AsyncBufRead;
+
+/// A collection of eDNE records that can be parsed from a file.
+pub trait Collection:
+    Default + Extend<Self::Record> + FromIterator<Self::Record>
+{
+    /// Record parsed from each line.
+    type Record: Record;
+
+    /// Name of the archive entry read by [`Collection::from_archive`], e.g.
+    /// "LOG_LOCALIDADE.TXT".
+    const ARCHIVE_ENTRY: &'static str;
+
+    /// Creates an empty collection with room for `capacity` records.
+    fn with_capacity(capacity: usize) -> Self;
+
+    /// Parses the collection from ISO-8859-1 encoded bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if decoding fails or any line has invalid data.
+    fn from_iso8859_1(bytes: &[u8]) -> Result<Self, ParseError> {
+        let parser = EdneParser::from_iso8859_1(bytes)?;
+        Self::from_parser(&parser)
+    }
+
+    /// Parses the collection from bytes in the given encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if decoding fails or any line has invalid data.
+    fn from_bytes(
+        bytes: &[u8],
+        encoding: Encoding,
+    ) -> Result<Self, ParseError> {
+        let parser = EdneParser::from_bytes(bytes, encoding)?;
+        Self::from_parser(&parser)
+    }
+
+    /// Parses the collection from a UTF-8 string (for testing).
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if any line has invalid data.
+    fn from_utf8(content: String) -> Result<Self, ParseError> {
+        let parser = EdneParser::from_utf8(content);
+        Self::from_parser(&parser)
+    }
+
+    /// Parses the collection from a file.
+    ///
+    /// The encoding is detected from the content (see [`Encoding::detect`]).
+    /// The file is memory-mapped and decoded one line at a time.
+    ///
+    /// The file must not be truncated or modified by another process while
+    /// it is being parsed. The mapping is read directly, and truncating a
+    /// mapped file makes the reads fault (SIGBUS on Unix), which is
+    /// undefined behaviour. Use [`Collection::from_reader`] for files that
+    /// may change.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if the file cannot be read or any line has
+    /// invalid data.
+    fn from_path(path: &Path) -> Result<Self, ParseError> {
+        let mut records = Self::default();
+        mapped::parse_path(path, Encoding::Auto, |record| {
+            records.extend(iter::once(record))
+        })?;
+        Ok(records)
+    }
+
+    /// Parses the collection from a reader, such as stdin.
+    ///
+    /// Lines are decoded as they are read, so the input is never buffered
+    /// as a whole. As with [`Collection::from_path`], the encoding is
+    /// detected with `Encoding::Auto`, here line by line.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if reading fails or any line has invalid data.
+    fn from_reader<R: Read>(reader: R) -> Result<Self, ParseError> {
+        let mut records = Self::default();
+        record_stream::parse_reader(reader, Encoding::Auto, |record| {
+            records.extend(iter::once(record))
+        })?;
+        Ok(records)
+    }
+
+    /// Parses the collection from the [`Collection::ARCHIVE_ENTRY`] entry of
+    /// an eDNE archive.
+    ///
+    /// The encoding of the entry is detected as with `Encoding::Auto`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if the entry is missing or cannot be extracted,
+    /// or any line has invalid data.
+    fn from_archive(archive: &Archive) -> Result<Self, ParseError> {
+        let parser = archive.parser(Self::ARCHIVE_ENTRY)?;
+        Self::from_parser(&parser)
+    }
+
+    /// Parses the collection from an async reader.
+    ///
+    /// Lines are decoded as they are read, so the input is never buffered
+    /// as a whole. As with [`Collection::from_path`], the encoding is
+    /// detected with `Encoding::Auto`, here line by line.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if reading fails or any line has invalid data.
+    #[cfg(feature = "async")]
+    fn from_async_read<R>(
+        reader: R,
+    ) -> impl Future<Output = Result<Self, ParseError>>
+    where
+        R: AsyncBufRead + Unpin,
+    {
+        async move {
+            let mut records = Self::default();
+            async_stream::parse_async_read(reader, Encoding::Auto, |record| {
+                records.extend(iter::once(record))
+            })
+            .await?;
+            Ok(records)
+        }
+    }
+
+    /// Parses the collection using a configured parser.
+    ///
+    /// Build the parser with [`EdneParser::with_layout`] to read fixed-width
+    /// files.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `ParseError` of the first invalid line.
+    fn from_parser(parser: &EdneParser) -> Result<Self, ParseError> {
+        let lines: Vec<_> = parser.located_lines().collect();
+        let mut records = Self::with_capacity(lines.len());
+
+        for line in &lines {
+            let record = parser.parse_located(line)?;
+            records.extend(iter::once(record));
+        }
+
+        Ok(records)
+    }
+
+    /// Parses the collection from ISO-8859-1 encoded bytes, skipping invalid
+    /// lines.
+    ///
+    /// Returns the records of the valid lines together with the errors of
+    /// the skipped ones. If `options` stop the parsing, the records read so
+    /// far are kept and [`LenientParse::aborted`] holds the reason.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if decoding fails.
+    fn from_iso8859_1_lenient(
+        bytes: &[u8],
+        options: &ParseOptions,
+    ) -> Result<LenientParse<Self>, ParseError> {
+        let parser = EdneParser::from_iso8859_1(bytes)?;
+        Ok(Self::from_parser_lenient(&parser, options))
+    }
+
+    /// Parses the collection using a configured parser, skipping invalid
+    /// lines.
+    fn from_parser_lenient(
+        parser: &EdneParser,
+        options: &ParseOptions,
+    ) -> LenientParse<Self> {
+        lenient::parse_lenient(parser, options)
+    }
+
+    /// Parses the collection from ISO-8859-1 encoded bytes on multiple
+    /// threads.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if decoding fails or any line has invalid data.
+    #[cfg(feature = "parallel")]
+    fn from_iso8859_1_parallel(bytes: &[u8]) -> Result<Self, ParseError>
+    where
+        Self::Record: Send,
+    {
+        let parser = EdneParser::from_iso8859_1(bytes)?;
+        Self::from_parser_parallel(&parser)
+    }
+
+    /// Parses the collection using a configured parser on multiple threads.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `ParseError` of the first invalid line in the file.
+    #[cfg(feature = "parallel")]
+    fn from_parser_parallel(parser: &EdneParser) -> Result<Self, ParseError>
+    where
+        Self::Record: Send,
+    {
+        let chunks = parallel::parse_parallel(parser)?;
+        let mut records =
+            Self::with_capacity(chunks.iter().map(Vec::len).sum());
+        records.extend(chunks.into_iter().flatten());
+        Ok(records)
+    }
+}
+
+/// A keyed collection that can be updated from a DELTA_* file.
+///
+/// A delta file has the columns of the base file followed by
+/// [`DeltaCollection::OPERATION_FIELD`]. Each collection only names its
+/// key and operation column; parsing and applying the deltas is
+/// implemented once here.
+pub trait DeltaCollection: Collection {
+    /// Key the records are indexed by.
+    type Key;
+
+    /// Name of the trailing operation column, e.g. "LOC_OPERACAO".
+    const OPERATION_FIELD: &'static str;
+
+    /// Returns the key `record` is stored under.
+    fn key(record: &Self::Record) -> Self::Key;
+
+    /// Inserts `record`, returning the record previously stored under its
+    /// key.
+    fn insert(&mut self, record: Self::Record) -> Option<Self::Record>;
+
+    /// Removes the record stored under `key`, returning it if it was
+    /// present.
+    fn remove(&mut self, key: &Self::Key) -> Option<Self::Record>;
+
+    /// Applies a delta record in place.
+    ///
+    /// Inserts and updates replace the entry with the same key; deletes
+    /// remove it. Returns the entry previously stored under that key.
+    fn apply_delta(
+        &mut self,
+        delta: Delta<Self::Record>,
+    ) -> Option<Self::Record> {
+        match delta.operation {
+            DeltaOperation::Insert | DeltaOperation::Update => {
+                DeltaCollection::insert(self, delta.record)
+            }
+            DeltaOperation::Delete => {
+                DeltaCollection::remove(self, &Self::key(&delta.record))
+            }
+        }
+    }
+
+    /// Parses delta records from ISO-8859-1 encoded bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if decoding fails or any line has invalid data.
+    fn deltas_from_iso8859_1(
+        bytes: &[u8],
+    ) -> Result<Vec<Delta<Self::Record>>, ParseError> {
+        let parser = EdneParser::from_iso8859_1(bytes)?;
+        Self::deltas_from_parser(&parser)
+    }
+
+    /// Parses delta records from UTF-8 string (for testing).
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if any line has invalid data.
+    fn deltas_from_utf8(
+        content: String,
+    ) -> Result<Vec<Delta<Self::Record>>, ParseError> {
+        let parser = EdneParser::from_utf8(content);
+        Self::deltas_from_parser(&parser)
+    }
+
+    /// Parses delta records using a configured parser.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `ParseError` of the first invalid line.
+    fn deltas_from_parser(
+        parser: &EdneParser,
+    ) -> Result<Vec<Delta<Self::Record>>, ParseError> {
+        delta::parse_deltas(
+            parser,
+            Self::OPERATION_FIELD,
+            <Self::Record as Record>::parse_line,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::ErrorKind,
+        models::NeighborhoodId,
+        parser::{archive::tests::build_zip, neighborhoods::Neighborhoods},
+    };
+    use std::{env, fs, process};
+
+    const SAMPLE_DATA: &str = "\
+55400@AC@16@Loteamento Jaguar@Lot Jaguar
+55401@AC@16@Centro@
+55402@AC@13@Vila Acre@";
+
+    fn sample() -> Neighborhoods {
+        Neighborhoods::from_utf8(SAMPLE_DATA.to_string()).unwrap()
+    }
+
+    fn assert_same(records: &Neighborhoods, expected: &Neighborhoods) {
+        assert_eq!(records.len(), expected.len());
+        for (id, neighborhood) in expected.iter() {
+            assert_eq!(records.get(id), Some(neighborhood));
+        }
+    }
+
+    #[test]
+    fn parse_from_bytes_decodes_encoding() {
+        let bytes = b"55401@AC@16@Jardim Am\xe9rica@";
+        let records = Neighborhoods::from_iso8859_1(bytes).unwrap();
+        let id = NeighborhoodId::new(55401);
+        assert_eq!(records.get(&id).unwrap().name, "Jardim América");
+
+        let utf8 = "55401@AC@16@Jardim América@".as_bytes();
+        let records = Neighborhoods::from_bytes(utf8, Encoding::Auto).unwrap();
+        assert_eq!(records.get(&id).unwrap().name, "Jardim América");
+    }
+
+    #[test]
+    fn parse_from_path() {
+        let path = env::temp_dir()
+            .join(format!("edne-collection-{}.txt", process::id()));
+        fs::write(&path, SAMPLE_DATA).unwrap();
+        let result = Neighborhoods::from_path(&path);
+        assert_same(&result.unwrap(), &sample());
+
+        // The encoding is detected, so UTF-8 files are read as well.
+        fs::write(&path, "55401@AC@16@Jardim América@").unwrap();
+        let result = Neighborhoods::from_path(&path);
+        fs::remove_file(&path).unwrap();
+
+        let records = result.unwrap();
+        let id = NeighborhoodId::new(55401);
+        assert_eq!(records.get(&id).unwrap().name, "Jardim América");
+    }
+
+    #[test]
+    fn parse_from_reader() {
+        let records = Neighborhoods::from_reader(SAMPLE_DATA.as_bytes());
+        assert_same(&records.unwrap(), &sample());
+
+        let result = Neighborhoods::from_reader(&b"16@AC"[..]);
+        assert!(matches!(result, Err(ParseError::FieldCount { .. })));
+
+        // As with from_path, both ISO-8859-1 and UTF-8 input are read.
+        let id = NeighborhoodId::new(55401);
+        for bytes in [
+            &b"55401@AC@16@Jardim Am\xe9rica@"[..],
+            "55401@AC@16@Jardim América@".as_bytes(),
+        ] {
+            let records = Neighborhoods::from_reader(bytes).unwrap();
+            assert_eq!(records.get(&id).unwrap().name, "Jardim América");
+        }
+    }
+
+    #[test]
+    fn parse_from_archive() {
+        let zip = build_zip(&[(
+            "eDNE_Basico/Delimitado/log_bairro.txt",
+            b"55400@AC@16@Loteamento Jaguar@Lot Jaguar\n55401@XX@16@Centro@",
+            true,
+        )]);
+        let archive = Archive::from_bytes(zip).unwrap();
+        let err = Neighborhoods::from_archive(&archive).unwrap_err();
+        let span = err.span().unwrap();
+
+        assert_eq!(
+            span.file_name.as_deref(),
+            Some("eDNE_Basico/Delimitado/log_bairro.txt")
+        );
+        assert_eq!(span.line, 2);
+
+        let archive = Archive::from_bytes(build_zip(&[])).unwrap();
+        let err = Neighborhoods::from_archive(&archive).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Archive);
+    }
+
+    #[test]
+    fn parse_lenient_keeps_records_read_before_abort() {
+        let data = format!("{SAMPLE_DATA}\n55403@XX@16@Centro@");
+        let options = ParseOptions::new().max_errors(0);
+        let outcome =
+            Neighborhoods::from_iso8859_1_lenient(data.as_bytes(), &options)
+                .unwrap();
+
+        assert_same(&outcome.records, &sample());
+        assert_eq!(outcome.errors.len(), 1);
+        assert_eq!(outcome.errors[0].field_name(), Some("UFE_SG"));
+        assert!(matches!(
+            outcome.aborted,
+            Some(ParseError::TooManyErrors { max: 0, line_number: 4 })
+        ));
+    }
+
+    #[test]
+    fn collect_and_extend() {
+        let mut records: Neighborhoods =
+            sample().iter().map(|(_, n)| n.clone()).collect();
+        assert_same(&records, &sample());
+
+        let more = Neighborhoods::from_utf8("1@AC@16@Bosque@".to_string());
+        records.extend(more.unwrap().iter().map(|(_, n)| n.clone()));
+        assert_eq!(records.len(), 4);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn parse_from_async_read() {
+        let records =
+            Neighborhoods::from_async_read(SAMPLE_DATA.as_bytes()).await;
+        assert_same(&records.unwrap(), &sample());
+
+        let result = Neighborhoods::from_async_read(&b"16@AC"[..]).await;
+        assert!(matches!(result, Err(ParseError::FieldCount { .. })));
+
+        let utf8 = "55401@AC@16@Jardim América@".as_bytes();
+        let records = Neighborhoods::from_async_read(utf8).await.unwrap();
+        let id = NeighborhoodId::new(55401);
+        assert_eq!(records.get(&id).unwrap().name, "Jardim América");
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parse_parallel_matches_sequential() {
+        let parser = EdneParser::from_utf8(SAMPLE_DATA.to_string());
+        let records = Neighborhoods::from_parser_parallel(&parser).unwrap();
+        assert_same(&records, &sample());
+    }
+}
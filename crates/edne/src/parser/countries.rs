@@ -0,0 +1,246 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+use std::collections::HashMap;
+
+use crate::{
+    models::country::Country,
+    parser::{
+        base::{EdneParser, ParseError},
+        collection::Collection,
+        record_stream::Record,
+    },
+};
+
+/// Expected number of fields in a country record.
+const COUNTRY_FIELD_COUNT: usize = 6;
+
+/// Collection of countries indexed by their two-letter code.
+#[derive(Debug, Clone)]
+pub struct Countries(HashMap<String, Country>);
+
+impl Countries {
+    /// Creates a new empty collection.
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Creates a collection with pre-allocated capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(HashMap::with_capacity(capacity))
+    }
+
+    /// Returns the number of countries.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the collection is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Gets a country by its two-letter code, ignoring case.
+    pub fn get(&self, code: &str) -> Option<&Country> {
+        self.0.get(&code.trim().to_ascii_uppercase())
+    }
+
+    /// Inserts a country into the collection.
+    pub fn insert(&mut self, country: Country) -> Option<Country> {
+        self.0.insert(country.code.clone(), country)
+    }
+
+    /// Returns an iterator over all countries.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Country)> {
+        self.0.iter()
+    }
+}
+
+impl Default for Countries {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collection for Countries {
+    type Record = Country;
+    const ARCHIVE_ENTRY: &'static str = "ECT_PAIS.TXT";
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity(capacity)
+    }
+}
+
+impl Extend<Country> for Countries {
+    fn extend<I: IntoIterator<Item = Country>>(&mut self, iter: I) {
+        for record in iter {
+            self.insert(record);
+        }
+    }
+}
+
+impl FromIterator<Country> for Countries {
+    fn from_iter<I: IntoIterator<Item = Country>>(iter: I) -> Self {
+        let mut records = Self::new();
+        records.extend(iter);
+        records
+    }
+}
+
+impl Record for Country {
+    const FIELDS: &'static [&'static str] = &[
+        "PAI_SG",
+        "PAI_SG_ALTERNATIVA",
+        "PAI_NO_PORTUGUES",
+        "PAI_NO_INGLES",
+        "PAI_NO_FRANCES",
+        "PAI_ABREVIATURA",
+    ];
+
+    fn parse_line(
+        parser: &EdneParser,
+        line: &str,
+        line_number: usize,
+    ) -> Result<Self, ParseError> {
+        parse_country_line(parser, line, line_number)
+    }
+}
+
+/// Parses a single ECT_PAIS line into a `Country` struct.
+///
+/// # Field order (6 fields):
+/// 1. PAI_SG - Two-letter country code
+/// 2. PAI_SG_ALTERNATIVA - Alternative country code (optional)
+/// 3. PAI_NO_PORTUGUES - Name in Portuguese
+/// 4. PAI_NO_INGLES - Name in English (optional)
+/// 5. PAI_NO_FRANCES - Name in French (optional)
+/// 6. PAI_ABREVIATURA - Abbreviated name (optional)
+fn parse_country_line(
+    parser: &EdneParser,
+    line: &str,
+    line_number: usize,
+) -> Result<Country, ParseError> {
+    let fields =
+        parser.parse_line_checked(line, COUNTRY_FIELD_COUNT, line_number)?;
+
+    let code = EdneParser::required_field(fields[0], "PAI_SG", line_number)?;
+    if code.len() != 2 || !code.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return Err(ParseError::InvalidValue {
+            field_name: "PAI_SG",
+            value: code,
+            reason: "country code must have exactly 2 letters".to_string(),
+            line_number,
+            source: None,
+            span: None,
+        });
+    }
+
+    let alternative_code = EdneParser::optional_field(fields[1]);
+    let name_portuguese = EdneParser::required_field(
+        fields[2],
+        "PAI_NO_PORTUGUES",
+        line_number,
+    )?;
+    let name_english = EdneParser::optional_field(fields[3]);
+    let name_french = EdneParser::optional_field(fields[4]);
+    let abbreviation = EdneParser::optional_field(fields[5]);
+
+    Ok(Country {
+        code: code.to_ascii_uppercase(),
+        alternative_code,
+        name_portuguese,
+        name_english,
+        name_french,
+        abbreviation,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_DATA: &str = "\
+AD@AND@Andorra@Andorra@Andorre@AD
+BR@BRA@Brasil@Brazil@Brésil@BR
+DE@DEU@Alemanha@Germany@Allemagne@DE
+XK@@Kosovo@@@";
+
+    #[test]
+    fn parse_sample_data() {
+        let countries = Countries::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        assert_eq!(countries.len(), 4);
+    }
+
+    #[test]
+    fn parse_country_with_all_fields() {
+        let countries = Countries::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        let germany = countries.get("DE").unwrap();
+
+        assert_eq!(germany.code, "DE");
+        assert_eq!(germany.alternative_code.as_deref(), Some("DEU"));
+        assert_eq!(germany.name_portuguese, "Alemanha");
+        assert_eq!(germany.name_english.as_deref(), Some("Germany"));
+        assert_eq!(germany.name_french.as_deref(), Some("Allemagne"));
+        assert_eq!(germany.abbreviation.as_deref(), Some("DE"));
+    }
+
+    #[test]
+    fn parse_country_without_optional_fields() {
+        let countries = Countries::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        let kosovo = countries.get("XK").unwrap();
+
+        assert_eq!(kosovo.alternative_code, None);
+        assert_eq!(kosovo.name_portuguese, "Kosovo");
+        assert_eq!(kosovo.name_english, None);
+        assert_eq!(kosovo.name_french, None);
+        assert_eq!(kosovo.abbreviation, None);
+    }
+
+    #[test]
+    fn get_ignores_case() {
+        let countries = Countries::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        assert_eq!(
+            countries.get(" br ").unwrap().name_english.as_deref(),
+            Some("Brazil")
+        );
+        assert!(countries.get("ZZ").is_none());
+    }
+
+    #[test]
+    fn parse_invalid_field_count() {
+        let invalid = "BR@BRA@Brasil@Brazil@Brésil";
+        let result = Countries::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::FieldCount { expected, got, .. } => {
+                assert_eq!(expected, 6);
+                assert_eq!(got, 5);
+            }
+            _ => panic!("Expected FieldCount error"),
+        }
+    }
+
+    #[test]
+    fn parse_invalid_code() {
+        let invalid = "BRA@BRA@Brasil@Brazil@Brésil@BR";
+        let result = Countries::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::InvalidValue { field_name, .. } => {
+                assert_eq!(field_name, "PAI_SG");
+            }
+            _ => panic!("Expected InvalidValue error"),
+        }
+    }
+}
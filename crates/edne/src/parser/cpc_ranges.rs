@@ -0,0 +1,244 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+use std::{collections::HashMap, str::FromStr};
+
+use crate::{
+    models::{CpcId, post_box_range::CpcPostBoxRange},
+    parser::{
+        base::{EdneParser, ParseError},
+        collection::Collection,
+        record_stream::Record,
+    },
+};
+
+/// Expected number of fields in a CPC post box range record.
+const CPC_RANGE_FIELD_COUNT: usize = 3;
+
+/// Collection of CPC post box ranges indexed by their CPC ID.
+#[derive(Debug, Clone)]
+pub struct CpcPostBoxRanges(HashMap<CpcId, Vec<CpcPostBoxRange>>);
+
+impl CpcPostBoxRanges {
+    /// Creates a new empty collection.
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Creates a collection with pre-allocated capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(HashMap::with_capacity(capacity))
+    }
+
+    /// Returns the number of ranges.
+    pub fn len(&self) -> usize {
+        self.0.values().map(Vec::len).sum()
+    }
+
+    /// Returns `true` if the collection is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Gets all ranges of a CPC.
+    pub fn get(&self, id: &CpcId) -> Option<&[CpcPostBoxRange]> {
+        self.0.get(id).map(Vec::as_slice)
+    }
+
+    /// Inserts a range into the collection.
+    pub fn insert(&mut self, range: CpcPostBoxRange) {
+        self.0.entry(range.cpc_id).or_default().push(range);
+    }
+
+    /// Returns an iterator over all ranges.
+    pub fn iter(&self) -> impl Iterator<Item = &CpcPostBoxRange> {
+        self.0.values().flatten()
+    }
+
+    /// Returns `true` if post box `number` belongs to a CPC `id`.
+    pub fn has_post_box(&self, id: &CpcId, number: u32) -> bool {
+        self.get(id)
+            .is_some_and(|ranges| ranges.iter().any(|r| r.contains(number)))
+    }
+}
+
+impl Default for CpcPostBoxRanges {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collection for CpcPostBoxRanges {
+    type Record = CpcPostBoxRange;
+    const ARCHIVE_ENTRY: &'static str = "LOG_FAIXA_CPC.TXT";
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity(capacity)
+    }
+}
+
+impl Extend<CpcPostBoxRange> for CpcPostBoxRanges {
+    fn extend<I: IntoIterator<Item = CpcPostBoxRange>>(&mut self, iter: I) {
+        for record in iter {
+            self.insert(record);
+        }
+    }
+}
+
+impl FromIterator<CpcPostBoxRange> for CpcPostBoxRanges {
+    fn from_iter<I: IntoIterator<Item = CpcPostBoxRange>>(iter: I) -> Self {
+        let mut records = Self::new();
+        records.extend(iter);
+        records
+    }
+}
+
+impl Record for CpcPostBoxRange {
+    const FIELDS: &'static [&'static str] =
+        &["CPC_NU", "CPC_INICIAL", "CPC_FINAL"];
+
+    fn parse_line(
+        parser: &EdneParser,
+        line: &str,
+        line_number: usize,
+    ) -> Result<Self, ParseError> {
+        parse_cpc_range_line(parser, line, line_number)
+    }
+}
+
+/// Parses a single LOG_FAIXA_CPC line into a `CpcPostBoxRange` struct.
+///
+/// # Field order (3 fields):
+/// 1. CPC_NU - CPC ID
+/// 2. CPC_INICIAL - First post box number
+/// 3. CPC_FINAL - Last post box number
+fn parse_cpc_range_line(
+    parser: &EdneParser,
+    line: &str,
+    line_number: usize,
+) -> Result<CpcPostBoxRange, ParseError> {
+    let fields =
+        parser.parse_line_checked(line, CPC_RANGE_FIELD_COUNT, line_number)?;
+
+    let id_str = EdneParser::required_field(fields[0], "CPC_NU", line_number)?;
+    let cpc_id =
+        CpcId::from_str(&id_str).map_err(|e| ParseError::InvalidValue {
+            field_name: "CPC_NU",
+            value: id_str,
+            reason: e.to_string(),
+            source: Some(e.into()),
+            line_number,
+            span: None,
+        })?;
+
+    let box_start =
+        EdneParser::parse_number(fields[1], "CPC_INICIAL", line_number)?;
+    let box_end: u32 =
+        EdneParser::parse_number(fields[2], "CPC_FINAL", line_number)?;
+    if box_start > box_end {
+        return Err(ParseError::InvalidValue {
+            field_name: "CPC_FINAL",
+            value: fields[2].to_string(),
+            reason: format!("range end is lower than start '{}'", box_start),
+            line_number,
+            source: None,
+            span: None,
+        });
+    }
+
+    Ok(CpcPostBoxRange { cpc_id, box_start, box_end })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_DATA: &str = "\
+1285@1@500
+1285@1001@1500
+3788@1@200
+4162@1@150";
+
+    #[test]
+    fn parse_sample_data() {
+        let ranges =
+            CpcPostBoxRanges::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        assert_eq!(ranges.len(), 4);
+    }
+
+    #[test]
+    fn parse_cpc_with_several_ranges() {
+        let ranges =
+            CpcPostBoxRanges::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        let found = ranges.get(&CpcId::new(1285)).unwrap();
+
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].box_start, 1);
+        assert_eq!(found[0].box_end, 500);
+        assert_eq!(found[1].box_start, 1001);
+        assert_eq!(found[1].box_end, 1500);
+    }
+
+    #[test]
+    fn has_post_box_in_range() {
+        let ranges =
+            CpcPostBoxRanges::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        let id = CpcId::new(1285);
+
+        assert!(ranges.has_post_box(&id, 250));
+        assert!(ranges.has_post_box(&id, 1234));
+        assert!(!ranges.has_post_box(&id, 750));
+        assert!(!ranges.has_post_box(&CpcId::new(99999), 250));
+    }
+
+    #[test]
+    fn parse_invalid_field_count() {
+        let invalid = "1285@1";
+        let result = CpcPostBoxRanges::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::FieldCount { expected, got, .. } => {
+                assert_eq!(expected, 3);
+                assert_eq!(got, 2);
+            }
+            _ => panic!("Expected FieldCount error"),
+        }
+    }
+
+    #[test]
+    fn parse_invalid_number() {
+        let invalid = "1285@abc@500";
+        let result = CpcPostBoxRanges::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::InvalidNumber { field_name, .. } => {
+                assert_eq!(field_name, "CPC_INICIAL");
+            }
+            _ => panic!("Expected InvalidNumber error"),
+        }
+    }
+
+    #[test]
+    fn parse_inverted_range() {
+        let invalid = "1285@500@0001";
+        let result = CpcPostBoxRanges::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::InvalidValue { field_name, value, .. } => {
+                assert_eq!(field_name, "CPC_FINAL");
+                assert_eq!(value, "0001");
+            }
+            _ => panic!("Expected InvalidValue error"),
+        }
+    }
+}
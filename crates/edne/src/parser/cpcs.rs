@@ -19,9 +19,14 @@ use std::{collections::HashMap, str::FromStr};
 use crate::{
     models::{
         LocalityId, Uf,
-        cpc::{Cpc, CpcId},
+        cpc::{Cpc, CpcId, CpcRef},
+    },
+    parser::{
+        base::{EdneParser, ParseError},
+        collection::{Collection, DeltaCollection},
+        helpers,
+        record_stream::{Record, RecordRef},
     },
-    parser::base::{EdneParser, ParseError},
 };
 
 /// Expected number of fields in a CPC record.
@@ -62,48 +67,87 @@ impl Cpcs {
         self.0.insert(cpc.id, cpc)
     }
 
+    /// Removes a CPC by ID, returning it if it was present.
+    pub fn remove(&mut self, id: &CpcId) -> Option<Cpc> {
+        self.0.remove(id)
+    }
+
     /// Returns an iterator over all CPCs.
     pub fn iter(&self) -> impl Iterator<Item = (&CpcId, &Cpc)> {
         self.0.iter()
     }
+}
+
+impl Default for Cpcs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collection for Cpcs {
+    type Record = Cpc;
+    const ARCHIVE_ENTRY: &'static str = "LOG_CPC.TXT";
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity(capacity)
+    }
+}
+
+impl DeltaCollection for Cpcs {
+    type Key = CpcId;
+    const OPERATION_FIELD: &'static str = "CPC_OPERACAO";
 
-    /// Parses CPCs from ISO-8859-1 encoded bytes.
-    ///
-    /// # Arguments
-    ///
-    /// * `bytes` - Raw file content in ISO-8859-1 encoding
-    ///
-    /// # Errors
-    ///
-    /// Returns `ParseError` if encoding fails or any line has invalid data.
-    pub fn from_iso8859_1(bytes: &[u8]) -> Result<Self, ParseError> {
-        let parser = EdneParser::from_iso8859_1(bytes)?;
-        Self::parse_with_parser(&parser)
+    fn key(record: &Cpc) -> CpcId {
+        record.id
     }
 
-    /// Parses CPCs from UTF-8 string (for testing).
-    pub fn from_utf8(content: String) -> Result<Self, ParseError> {
-        let parser = EdneParser::from_utf8(content);
-        Self::parse_with_parser(&parser)
+    fn insert(&mut self, record: Cpc) -> Option<Cpc> {
+        self.0.insert(record.id, record)
     }
 
-    /// Internal method to parse CPCs using a configured parser.
-    fn parse_with_parser(parser: &EdneParser) -> Result<Self, ParseError> {
-        let lines: Vec<_> = parser.lines().collect();
-        let mut cpcs = Self::with_capacity(lines.len());
+    fn remove(&mut self, key: &CpcId) -> Option<Cpc> {
+        self.0.remove(key)
+    }
+}
 
-        for (line_number, line) in lines {
-            let cpc = parse_cpc_line(parser, line, line_number)?;
-            cpcs.insert(cpc);
+impl Extend<Cpc> for Cpcs {
+    fn extend<I: IntoIterator<Item = Cpc>>(&mut self, iter: I) {
+        for record in iter {
+            self.insert(record);
         }
+    }
+}
 
-        Ok(cpcs)
+impl FromIterator<Cpc> for Cpcs {
+    fn from_iter<I: IntoIterator<Item = Cpc>>(iter: I) -> Self {
+        let mut records = Self::new();
+        records.extend(iter);
+        records
     }
 }
 
-impl Default for Cpcs {
-    fn default() -> Self {
-        Self::new()
+impl Record for Cpc {
+    const FIELDS: &'static [&'static str] =
+        &["CPC_NU", "UFE_SG", "LOC_NU", "CPC_NO", "CPC_ENDERECO", "CEP"];
+
+    fn parse_line(
+        parser: &EdneParser,
+        line: &str,
+        line_number: usize,
+    ) -> Result<Self, ParseError> {
+        parse_cpc_line(parser, line, line_number)
+    }
+}
+
+impl<'a> RecordRef<'a> for CpcRef<'a> {
+    type Owned = Cpc;
+
+    fn parse_line(
+        parser: &EdneParser,
+        line: &'a str,
+        line_number: usize,
+    ) -> Result<Self, ParseError> {
+        parse_cpc_ref_line(parser, line, line_number)
     }
 }
 
@@ -121,49 +165,66 @@ fn parse_cpc_line(
     line: &str,
     line_number: usize,
 ) -> Result<Cpc, ParseError> {
+    parse_cpc_ref_line(parser, line, line_number).map(|r| r.to_owned())
+}
+
+/// Parses a single line into a borrowed `CpcRef`, without allocating its
+/// text fields. The field order is the same as [`parse_cpc_line`].
+fn parse_cpc_ref_line<'a>(
+    parser: &EdneParser,
+    line: &'a str,
+    line_number: usize,
+) -> Result<CpcRef<'a>, ParseError> {
     let fields =
         parser.parse_line_checked(line, CPC_FIELD_COUNT, line_number)?;
 
     // Parse required fields
-    let id_str = EdneParser::required_field(fields[0], "CPC_NU", line_number)?;
+    let id_str = EdneParser::required_str(fields[0], "CPC_NU", line_number)?;
     let id =
-        CpcId::from_str(&id_str).map_err(|e| ParseError::InvalidValue {
+        CpcId::from_str(id_str).map_err(|e| ParseError::InvalidValue {
             field_name: "CPC_NU",
-            value: id_str,
+            value: id_str.to_string(),
             reason: e.to_string(),
+            source: Some(e.into()),
             line_number,
+            span: None,
         })?;
 
-    let uf_str = EdneParser::required_field(fields[1], "UFE_SG", line_number)?;
-    let uf = Uf::from_str(&uf_str).map_err(|e| ParseError::InvalidValue {
+    let uf_str = EdneParser::required_str(fields[1], "UFE_SG", line_number)?;
+    let uf = Uf::from_str(uf_str).map_err(|e| ParseError::InvalidValue {
         field_name: "UFE_SG",
-        value: uf_str,
+        value: uf_str.to_string(),
         reason: e.to_string(),
+        source: Some(e.into()),
         line_number,
+        span: None,
     })?;
 
     let loc_id_str =
-        EdneParser::required_field(fields[2], "LOC_NU", line_number)?;
-    let locality_id = LocalityId::from_str(&loc_id_str).map_err(|e| {
+        EdneParser::required_str(fields[2], "LOC_NU", line_number)?;
+    let locality_id = LocalityId::from_str(loc_id_str).map_err(|e| {
         ParseError::InvalidValue {
             field_name: "LOC_NU",
-            value: loc_id_str,
+            value: loc_id_str.to_string(),
             reason: e.to_string(),
+            source: Some(e.into()),
             line_number,
+            span: None,
         }
     })?;
 
-    let name = EdneParser::required_field(fields[3], "CPC_NO", line_number)?;
+    let name = EdneParser::required_str(fields[3], "CPC_NO", line_number)?;
     let address =
-        EdneParser::required_field(fields[4], "CPC_ENDERECO", line_number)?;
-    let cep = EdneParser::required_field(fields[5], "CEP", line_number)?;
+        EdneParser::required_str(fields[4], "CPC_ENDERECO", line_number)?;
+    let cep = helpers::required_cep(fields[5], "CEP", line_number)?;
 
-    Ok(Cpc { id, uf, locality_id, name, address, cep })
+    Ok(CpcRef { id, uf, locality_id, name, address, cep })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{error::ValueError, models::Cep};
 
     const SAMPLE_DATA: &str = "\
 1285@AL@158@Conjunto Mutiro@Quadra 1 n 37 - Conj.Mutiro - Rio Largo@57100990
@@ -199,7 +260,7 @@ mod tests {
         assert_eq!(cpc.locality_id, LocalityId::new(158));
         assert_eq!(cpc.name, "Conjunto Mutiro");
         assert_eq!(cpc.address, "Quadra 1 n 37 - Conj.Mutiro - Rio Largo");
-        assert_eq!(cpc.cep, "57100990");
+        assert_eq!(cpc.cep, Cep::new(57100990));
     }
 
     #[test]
@@ -234,8 +295,22 @@ mod tests {
         let cpcs = Cpcs::from_utf8(SAMPLE_DATA.to_string()).unwrap();
         let id = CpcId::new(1285);
         let cpc = cpcs.get(&id).unwrap();
-        assert_eq!(cpc.cep.len(), 8);
-        assert!(cpc.cep.chars().all(|c| c.is_ascii_digit()));
+        assert_eq!(cpc.cep.to_string(), "57100-990");
+        assert_eq!(cpc.cep.prefix(3), 571);
+    }
+
+    #[test]
+    fn parse_malformed_cep() {
+        let invalid = "1285@AL@158@Conjunto Mutiro@Quadra 1@5710099X";
+        let err = Cpcs::from_utf8(invalid.to_string()).unwrap_err();
+        match &err {
+            ParseError::InvalidValue { field_name, value, .. } => {
+                assert_eq!(*field_name, "CEP");
+                assert_eq!(value, "5710099X");
+            }
+            e => panic!("Expected InvalidValue error, got {e:?}"),
+        }
+        assert!(matches!(err.value_error(), Some(ValueError::Cep(_))));
     }
 
     #[test]
@@ -317,4 +392,39 @@ mod tests {
         let cpc = cpcs.get(&id).unwrap();
         assert!(cpc.address.contains(','));
     }
+
+    const DELTA_DATA: &str = "\
+1285@AL@158@Nome Novo@Quadra 1 n 37 - Conj.Mutiro - Rio Largo@57100990@UPD
+3788@AL@158@Utinga Leo@Rua do Hospital s/n@57100993@DEL
+999999@AL@158@Conjunto Mutiro@Quadra 1 n 37 - Conj.Mutiro - Rio Largo@57100990@INS";
+
+    #[test]
+    fn apply_delta_to_base() {
+        let mut cpcs = Cpcs::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        let before = cpcs.len();
+        let deltas = Cpcs::deltas_from_utf8(DELTA_DATA.to_string()).unwrap();
+        assert_eq!(deltas.len(), 3);
+
+        for delta in deltas {
+            cpcs.apply_delta(delta);
+        }
+
+        assert_eq!(cpcs.len(), before);
+        assert_eq!(cpcs.get(&CpcId::new(1285)).unwrap().name, "Nome Novo");
+        assert!(cpcs.get(&CpcId::new(3788)).is_none());
+        assert!(cpcs.get(&CpcId::new(999999)).is_some());
+    }
+
+    #[test]
+    fn parse_delta_invalid_operation() {
+        let invalid =
+            "3788@AL@158@Utinga Leo@Rua do Hospital s/n@57100993@XXX";
+        let result = Cpcs::deltas_from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::InvalidValue { field_name, .. } => {
+                assert_eq!(field_name, "CPC_OPERACAO");
+            }
+            _ => panic!("Expected InvalidValue error"),
+        }
+    }
 }
@@ -0,0 +1,224 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+//! Shared parsing of DELTA_* update files.
+//!
+//! A delta line is a base line followed by one extra field holding the
+//! operation code, so each collection reuses its own line parser for the
+//! leading fields. Both delimited and fixed-width layouts are supported.
+
+use std::str::FromStr;
+
+use crate::{
+    models::delta::{Delta, DeltaOperation},
+    parser::{
+        base::{EdneParser, ParseError},
+        record_stream::Record,
+    },
+};
+
+/// Parses every line of a delta file with `parse_line` for the base fields.
+///
+/// Errors carry a span located with the columns of `T` followed by the
+/// operation column.
+///
+/// # Arguments
+///
+/// * `parser` - Parser holding the delta file content
+/// * `operation_field` - Name of the trailing operation column
+/// * `parse_line` - Line parser of the base file
+///
+/// # Errors
+///
+/// Returns `ParseError` if any line has invalid base fields or an invalid
+/// operation code.
+pub(crate) fn parse_deltas<T, F>(
+    parser: &EdneParser,
+    operation_field: &'static str,
+    parse_line: F,
+) -> Result<Vec<Delta<T>>, ParseError>
+where
+    T: Record,
+    F: Fn(&EdneParser, &str, usize) -> Result<T, ParseError>,
+{
+    // The base line parser must not see the operation column.
+    let base_parser = EdneParser::from_utf8(String::new())
+        .with_layout(parser.layout().without_last());
+    let fields: Vec<&str> =
+        T::FIELDS.iter().copied().chain([operation_field]).collect();
+
+    parser
+        .located_lines()
+        .map(|line| {
+            parse_delta_line(
+                parser,
+                &base_parser,
+                line.text,
+                line.number,
+                operation_field,
+                &parse_line,
+            )
+            .map_err(|e| parser.locate(e, &fields, line.text, line.offset))
+        })
+        .collect()
+}
+
+/// Splits the trailing operation code off `line` and parses both parts.
+fn parse_delta_line<T, F>(
+    parser: &EdneParser,
+    base_parser: &EdneParser,
+    line: &str,
+    line_number: usize,
+    operation_field: &'static str,
+    parse_line: &F,
+) -> Result<Delta<T>, ParseError>
+where
+    F: Fn(&EdneParser, &str, usize) -> Result<T, ParseError>,
+{
+    let (base, op_str) =
+        parser.layout().split_last(line).ok_or(ParseError::EmptyField {
+            field_name: operation_field,
+            line_number,
+            span: None,
+        })?;
+
+    let op_str =
+        EdneParser::required_field(op_str, operation_field, line_number)?;
+    let operation = DeltaOperation::from_str(&op_str).map_err(|e| {
+        ParseError::InvalidValue {
+            field_name: operation_field,
+            value: op_str,
+            reason: e.to_string(),
+            source: Some(e.into()),
+            line_number,
+            span: None,
+        }
+    })?;
+
+    let record = parse_line(base_parser, base, line_number)?;
+
+    Ok(Delta { operation, record })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::base::Layout;
+
+    #[derive(Debug, PartialEq)]
+    struct Pair(u32, String);
+
+    impl Record for Pair {
+        const FIELDS: &'static [&'static str] = &["ID", "NAME"];
+
+        fn parse_line(
+            parser: &EdneParser,
+            line: &str,
+            line_number: usize,
+        ) -> Result<Self, ParseError> {
+            parse_pair(parser, line, line_number)
+        }
+    }
+
+    fn parse_pair(
+        parser: &EdneParser,
+        line: &str,
+        line_number: usize,
+    ) -> Result<Pair, ParseError> {
+        let fields = parser.parse_line_checked(line, 2, line_number)?;
+        let id = EdneParser::parse_number(fields[0], "ID", line_number)?;
+        let name = EdneParser::required_field(fields[1], "NAME", line_number)?;
+        Ok(Pair(id, name))
+    }
+
+    #[test]
+    fn parse_deltas_with_all_operations() {
+        let parser =
+            EdneParser::from_utf8("1@One@INS\n2@Two@UPD\n3@Three@DEL".into());
+        let deltas = parse_deltas(&parser, "OPERACAO", parse_pair).unwrap();
+
+        assert_eq!(deltas.len(), 3);
+        assert_eq!(deltas[0].operation, DeltaOperation::Insert);
+        assert_eq!(deltas[0].record, Pair(1, "One".to_string()));
+        assert_eq!(deltas[1].operation, DeltaOperation::Update);
+        assert_eq!(deltas[2].operation, DeltaOperation::Delete);
+    }
+
+    #[test]
+    fn parse_deltas_fixed_width() {
+        let parser = EdneParser::from_utf8("  1One  INS\n  2Two  DEL".into())
+            .with_layout(Layout::fixed_width([0, 3, 8]));
+        let deltas = parse_deltas(&parser, "OPERACAO", parse_pair).unwrap();
+
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(deltas[0].operation, DeltaOperation::Insert);
+        assert_eq!(deltas[0].record, Pair(1, "One".to_string()));
+        assert_eq!(deltas[1].operation, DeltaOperation::Delete);
+        assert_eq!(deltas[1].record, Pair(2, "Two".to_string()));
+    }
+
+    #[test]
+    fn parse_delta_without_operation() {
+        let parser = EdneParser::from_utf8("1@One".into());
+        let result = parse_deltas(&parser, "OPERACAO", parse_pair);
+        match result.unwrap_err() {
+            ParseError::InvalidValue { field_name, .. } => {
+                assert_eq!(field_name, "OPERACAO");
+            }
+            _ => panic!("Expected InvalidValue error"),
+        }
+    }
+
+    #[test]
+    fn parse_delta_with_empty_operation() {
+        let parser = EdneParser::from_utf8("1@One@".into());
+        let result = parse_deltas(&parser, "OPERACAO", parse_pair);
+        match result.unwrap_err() {
+            ParseError::EmptyField { field_name, .. } => {
+                assert_eq!(field_name, "OPERACAO");
+            }
+            _ => panic!("Expected EmptyField error"),
+        }
+    }
+
+    #[test]
+    fn parse_delta_with_invalid_base_fields() {
+        let parser = EdneParser::from_utf8("1@INS".into());
+        let result = parse_deltas(&parser, "OPERACAO", parse_pair);
+        assert!(matches!(
+            result.unwrap_err(),
+            ParseError::FieldCount { expected: 2, got: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn parse_delta_error_spans() {
+        let parser = EdneParser::from_utf8("1@One@INS\n2@Two@XYZ".into());
+        let err = parse_deltas(&parser, "OPERACAO", parse_pair).unwrap_err();
+        let span = err.span().unwrap();
+        assert_eq!(span.line, 2);
+        assert_eq!(span.column, 7);
+        assert_eq!(span.field_index, Some(2));
+        assert_eq!(span.byte_offset, 16);
+
+        let parser = EdneParser::from_utf8("1@One@INS\nx@Two@UPD".into());
+        let err = parse_deltas(&parser, "OPERACAO", parse_pair).unwrap_err();
+        let span = err.span().unwrap();
+        assert_eq!(span.column, 1);
+        assert_eq!(span.field_index, Some(0));
+        assert_eq!(span.byte_offset, 10);
+    }
+}
@@ -0,0 +1,254 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+//! Character encodings of eDNE files.
+//!
+//! Correios distributes the files in ISO-8859-1, but some mirrors re-encode
+//! them as UTF-8 or Windows-1252. Decoding them with the wrong encoding
+//! silently produces mojibake such as "SÃ£o Paulo".
+
+use std::{fmt, str::FromStr};
+
+use crate::parser::base::ParseError;
+
+/// Unicode code points of Windows-1252 bytes 0x80-0x9F. `None` marks the
+/// five bytes the encoding leaves undefined.
+const WINDOWS_1252_HIGH: [Option<char>; 32] = [
+    Some('\u{20AC}'),
+    None,
+    Some('\u{201A}'),
+    Some('\u{0192}'),
+    Some('\u{201E}'),
+    Some('\u{2026}'),
+    Some('\u{2020}'),
+    Some('\u{2021}'),
+    Some('\u{02C6}'),
+    Some('\u{2030}'),
+    Some('\u{0160}'),
+    Some('\u{2039}'),
+    Some('\u{0152}'),
+    None,
+    Some('\u{017D}'),
+    None,
+    None,
+    Some('\u{2018}'),
+    Some('\u{2019}'),
+    Some('\u{201C}'),
+    Some('\u{201D}'),
+    Some('\u{2022}'),
+    Some('\u{2013}'),
+    Some('\u{2014}'),
+    Some('\u{02DC}'),
+    Some('\u{2122}'),
+    Some('\u{0161}'),
+    Some('\u{203A}'),
+    Some('\u{0153}'),
+    None,
+    Some('\u{017E}'),
+    Some('\u{0178}'),
+];
+
+/// Character encoding of an eDNE file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Encoding {
+    /// ISO-8859-1, the encoding of the official distribution (default).
+    #[default]
+    Latin1,
+    /// Windows-1252, a superset of ISO-8859-1 with printable 0x80-0x9F.
+    Windows1252,
+    /// UTF-8.
+    Utf8,
+    /// Detect the encoding from the content (see [`Encoding::detect`]).
+    Auto,
+}
+
+impl Encoding {
+    /// Guesses the encoding of `bytes`.
+    ///
+    /// - Valid UTF-8 with non-ASCII characters is `Utf8`.
+    /// - Otherwise, bytes in 0x80-0x9F (control characters in ISO-8859-1)
+    ///   mean `Windows1252`.
+    /// - Everything else is `Latin1`.
+    pub fn detect(bytes: &[u8]) -> Self {
+        if bytes.is_ascii() {
+            return Self::Latin1;
+        }
+        if std::str::from_utf8(bytes).is_ok() {
+            return Self::Utf8;
+        }
+        if bytes.iter().any(|b| (0x80..=0x9F).contains(b)) {
+            return Self::Windows1252;
+        }
+        Self::Latin1
+    }
+
+    /// Returns the number of bytes `text` takes in this encoding.
+    ///
+    /// Single-byte encodings take one byte per character. `Auto` is treated
+    /// as single-byte, like the distribution files it defaults to.
+    pub(crate) fn encoded_len(self, text: &str) -> usize {
+        match self {
+            Self::Utf8 => text.len(),
+            Self::Latin1 | Self::Windows1252 | Self::Auto => {
+                text.chars().count()
+            }
+        }
+    }
+
+    /// Decodes `bytes` into a UTF-8 string.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError::EncodingError` if `bytes` is not valid in this
+    /// encoding.
+    pub fn decode(self, bytes: &[u8]) -> Result<String, ParseError> {
+        match self {
+            Self::Latin1 => Ok(bytes.iter().map(|&b| b as char).collect()),
+            Self::Windows1252 => decode_windows_1252(bytes),
+            Self::Utf8 => String::from_utf8(bytes.to_vec()).map_err(|e| {
+                ParseError::EncodingError(format!(
+                    "invalid UTF-8 sequence at byte {}",
+                    e.utf8_error().valid_up_to()
+                ))
+            }),
+            Self::Auto => Self::detect(bytes).decode(bytes),
+        }
+    }
+}
+
+impl FromStr for Encoding {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "latin1" | "latin-1" | "iso-8859-1" | "iso8859-1" => {
+                Ok(Self::Latin1)
+            }
+            "windows-1252" | "windows1252" | "cp1252" => Ok(Self::Windows1252),
+            "utf-8" | "utf8" => Ok(Self::Utf8),
+            "auto" => Ok(Self::Auto),
+            other => Err(ParseError::EncodingError(format!(
+                "unknown encoding '{}'",
+                other
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Latin1 => "ISO-8859-1",
+            Self::Windows1252 => "Windows-1252",
+            Self::Utf8 => "UTF-8",
+            Self::Auto => "auto",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Decodes Windows-1252 bytes, failing on the undefined ones.
+fn decode_windows_1252(bytes: &[u8]) -> Result<String, ParseError> {
+    let mut result = String::with_capacity(bytes.len());
+    for (offset, &byte) in bytes.iter().enumerate() {
+        let ch = match byte {
+            0x80..=0x9F => WINDOWS_1252_HIGH[usize::from(byte - 0x80)]
+                .ok_or_else(|| {
+                    ParseError::EncodingError(format!(
+                        "byte 0x{:02X} at offset {} is not defined in \
+                         Windows-1252",
+                        byte, offset
+                    ))
+                })?,
+            _ => byte as char,
+        };
+        result.push(ch);
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAO_PAULO_LATIN1: &[u8] = b"S\xe3o Paulo";
+
+    #[test]
+    fn detect_encodings() {
+        assert_eq!(Encoding::detect(b"Rio Branco"), Encoding::Latin1);
+        assert_eq!(Encoding::detect(SAO_PAULO_LATIN1), Encoding::Latin1);
+        assert_eq!(Encoding::detect("São Paulo".as_bytes()), Encoding::Utf8);
+        assert_eq!(
+            Encoding::detect(b"Rua \x93Dois\x94 de Julho"),
+            Encoding::Windows1252
+        );
+    }
+
+    #[test]
+    fn decode_latin1() {
+        let result = Encoding::Latin1.decode(SAO_PAULO_LATIN1).unwrap();
+        assert_eq!(result, "São Paulo");
+    }
+
+    #[test]
+    fn decode_utf8() {
+        let result = Encoding::Utf8.decode("São Paulo".as_bytes()).unwrap();
+        assert_eq!(result, "São Paulo");
+    }
+
+    #[test]
+    fn decode_utf8_invalid() {
+        match Encoding::Utf8.decode(SAO_PAULO_LATIN1).unwrap_err() {
+            ParseError::EncodingError(msg) => assert!(msg.contains("byte 1")),
+            _ => panic!("Expected EncodingError"),
+        }
+    }
+
+    #[test]
+    fn decode_windows_1252() {
+        let result = Encoding::Windows1252
+            .decode(b"Rua \x93Dois\x94 de Julho \x96 S\xe3o Paulo")
+            .unwrap();
+        assert_eq!(
+            result,
+            "Rua \u{201C}Dois\u{201D} de Julho \u{2013} São Paulo"
+        );
+    }
+
+    #[test]
+    fn decode_windows_1252_undefined_byte() {
+        let result = Encoding::Windows1252.decode(b"abc\x81");
+        assert!(matches!(result, Err(ParseError::EncodingError(_))));
+    }
+
+    #[test]
+    fn decode_auto_avoids_mojibake() {
+        let utf8 = Encoding::Auto.decode("São Paulo".as_bytes()).unwrap();
+        let latin1 = Encoding::Auto.decode(SAO_PAULO_LATIN1).unwrap();
+        assert_eq!(utf8, "São Paulo");
+        assert_eq!(latin1, "São Paulo");
+    }
+
+    #[test]
+    fn encoding_from_str() {
+        assert_eq!(
+            Encoding::from_str("cp1252").unwrap(),
+            Encoding::Windows1252
+        );
+        assert_eq!(Encoding::from_str("UTF-8").unwrap(), Encoding::Utf8);
+        assert!(Encoding::from_str("ebcdic").is_err());
+    }
+}
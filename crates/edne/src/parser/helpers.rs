@@ -13,3 +13,130 @@
 // ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
 // OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 //
+
+use std::str::FromStr;
+
+use crate::{
+    models::Cep,
+    parser::base::{EdneParser, ParseError},
+};
+
+/// Parses a required CEP field.
+///
+/// # Errors
+///
+/// Returns `ParseError::EmptyField` if the field is empty, or
+/// `ParseError::InvalidValue` if it is not a valid CEP.
+pub fn required_cep(
+    field: &str,
+    field_name: &'static str,
+    line_number: usize,
+) -> Result<Cep, ParseError> {
+    let value = EdneParser::required_str(field, field_name, line_number)?;
+    parse_cep(value, field_name, line_number)
+}
+
+/// Parses an optional CEP field, returning `None` when it is empty.
+///
+/// # Errors
+///
+/// Returns `ParseError::InvalidValue` if the field is not a valid CEP.
+pub fn optional_cep(
+    field: &str,
+    field_name: &'static str,
+    line_number: usize,
+) -> Result<Option<Cep>, ParseError> {
+    EdneParser::optional_str(field)
+        .map(|value| parse_cep(value, field_name, line_number))
+        .transpose()
+}
+
+fn parse_cep(
+    value: &str,
+    field_name: &'static str,
+    line_number: usize,
+) -> Result<Cep, ParseError> {
+    Cep::from_str(value).map_err(|e| ParseError::InvalidValue {
+        field_name,
+        value: value.to_string(),
+        reason: e.to_string(),
+        source: Some(e.into()),
+        line_number,
+        span: None,
+    })
+}
+
+/// Parses the first and last CEP of a range given as `(field, field_name)`.
+///
+/// # Errors
+///
+/// Returns `ParseError` if either CEP is invalid, or
+/// `ParseError::InvalidValue` if the range ends before it starts.
+pub fn required_cep_range(
+    start: (&str, &'static str),
+    end: (&str, &'static str),
+    line_number: usize,
+) -> Result<(Cep, Cep), ParseError> {
+    let cep_start = required_cep(start.0, start.1, line_number)?;
+    let cep_end = required_cep(end.0, end.1, line_number)?;
+    if cep_start > cep_end {
+        return Err(ParseError::InvalidValue {
+            field_name: end.1,
+            value: end.0.trim().to_string(),
+            reason: format!("range end is lower than start '{}'", cep_start),
+            line_number,
+            source: None,
+            span: None,
+        });
+    }
+    Ok((cep_start, cep_end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ValueError;
+
+    #[test]
+    fn required_cep_valid() {
+        let cep = required_cep("69900000", "CEP", 1).unwrap();
+        assert_eq!(cep, Cep::new(69900000));
+    }
+
+    #[test]
+    fn required_cep_invalid() {
+        match required_cep("6990000a", "CEP", 3).unwrap_err() {
+            ParseError::InvalidValue {
+                field_name,
+                source,
+                line_number,
+                ..
+            } => {
+                assert_eq!(field_name, "CEP");
+                assert_eq!(line_number, 3);
+                assert!(matches!(source, Some(ValueError::Cep(_))));
+            }
+            e => panic!("Expected InvalidValue error, got {e:?}"),
+        }
+    }
+
+    #[test]
+    fn optional_cep_empty() {
+        assert_eq!(optional_cep("", "CEP", 1).unwrap(), None);
+    }
+
+    #[test]
+    fn required_cep_range_inverted() {
+        let result = required_cep_range(
+            ("69999999", "CEP_INI"),
+            ("69900000", "CEP_FIM"),
+            1,
+        );
+        match result.unwrap_err() {
+            ParseError::InvalidValue { field_name, .. } => {
+                assert_eq!(field_name, "CEP_FIM");
+            }
+            _ => panic!("Expected InvalidValue error"),
+        }
+    }
+}
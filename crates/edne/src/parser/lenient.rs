@@ -0,0 +1,252 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+//! Lenient parsing that skips invalid lines instead of failing fast.
+//!
+//! [`Collection::from_iso8859_1_lenient`] and
+//! [`Collection::from_parser_lenient`] return the records parsed from valid
+//! lines together with the errors of the skipped ones.
+//!
+//! [`Collection::from_iso8859_1_lenient`]:
+//! crate::parser::Collection::from_iso8859_1_lenient
+//! [`Collection::from_parser_lenient`]:
+//! crate::parser::Collection::from_parser_lenient
+
+use std::{collections::HashMap, iter};
+
+use crate::parser::{
+    base::{EdneParser, ParseError},
+    record_stream::Record,
+};
+
+/// What to do with a line whose error is in a given field.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum FieldPolicy {
+    /// Skip the line and report the error (default).
+    #[default]
+    Report,
+    /// Skip the line without reporting the error.
+    Ignore,
+    /// Stop parsing and return the error.
+    Abort,
+}
+
+/// Options of the lenient parsing mode.
+///
+/// # Example
+///
+/// ```rust
+/// use edne::parser::lenient::{FieldPolicy, ParseOptions};
+///
+/// let options = ParseOptions::new()
+///     .max_errors(100)
+///     .field_policy("LOG_STA_TLO", FieldPolicy::Ignore)
+///     .field_policy("LOG_NU", FieldPolicy::Abort);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ParseOptions {
+    max_errors: Option<usize>,
+    field_policies: HashMap<&'static str, FieldPolicy>,
+}
+
+impl ParseOptions {
+    /// Creates options with no error limit and `FieldPolicy::Report` for
+    /// every field.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stops parsing once more than `max` errors have been reported.
+    pub fn max_errors(mut self, max: usize) -> Self {
+        self.max_errors = Some(max);
+        self
+    }
+
+    /// Sets the policy for errors in `field_name` (e.g. "LOG_NU").
+    pub fn field_policy(
+        mut self,
+        field_name: &'static str,
+        policy: FieldPolicy,
+    ) -> Self {
+        self.field_policies.insert(field_name, policy);
+        self
+    }
+
+    /// Returns the policy that applies to `error`.
+    ///
+    /// Errors not tied to a field (e.g. wrong field count) are reported.
+    pub fn policy_for(&self, error: &ParseError) -> FieldPolicy {
+        error
+            .field_name()
+            .and_then(|name| self.field_policies.get(name).copied())
+            .unwrap_or_default()
+    }
+}
+
+/// Outcome of a lenient parse.
+///
+/// Parsing stops early when a field with `FieldPolicy::Abort` is invalid or
+/// too many errors are reported. The records and errors gathered up to that
+/// point are kept, and `aborted` holds the reason.
+#[derive(Debug)]
+pub struct LenientParse<T> {
+    /// Records parsed from the valid lines.
+    pub records: T,
+    /// Reported errors of the skipped lines, in file order.
+    pub errors: Vec<ParseError>,
+    /// Error that stopped the parsing, or `None` if every line was read.
+    pub aborted: Option<ParseError>,
+}
+
+impl<T> LenientParse<T> {
+    /// Returns `true` if every line was read.
+    pub fn is_complete(&self) -> bool {
+        self.aborted.is_none()
+    }
+}
+
+/// Parses every line of `parser` into `T`, skipping invalid lines.
+///
+/// Stops at the first invalid field with `FieldPolicy::Abort`, or with
+/// `ParseError::TooManyErrors` once the reported errors exceed the maximum.
+pub(crate) fn parse_lenient<R, T>(
+    parser: &EdneParser,
+    options: &ParseOptions,
+) -> LenientParse<T>
+where
+    R: Record,
+    T: Default + Extend<R>,
+{
+    let mut outcome = LenientParse {
+        records: T::default(),
+        errors: Vec::new(),
+        aborted: None,
+    };
+
+    for line in parser.located_lines() {
+        let line_number = line.number;
+        let error = match parser.parse_located::<R>(&line) {
+            Ok(record) => {
+                outcome.records.extend(iter::once(record));
+                continue;
+            }
+            Err(error) => error,
+        };
+
+        match options.policy_for(&error) {
+            FieldPolicy::Report => {
+                outcome.errors.push(error);
+                if let Some(max) = options.max_errors
+                    && outcome.errors.len() > max
+                {
+                    outcome.aborted =
+                        Some(ParseError::TooManyErrors { max, line_number });
+                    break;
+                }
+            }
+            FieldPolicy::Ignore => {}
+            FieldPolicy::Abort => {
+                outcome.aborted = Some(error);
+                break;
+            }
+        }
+    }
+
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Neighborhood, NeighborhoodId};
+
+    const SAMPLE_DATA: &str = "\
+55400@AC@16@Loteamento Jaguar@Lot Jaguar
+abc@AC@16@Loteamento Santa Luzia@Lot Sta Luzia
+55403@XX@16@Habitasa@Habitasa
+55404@AC@16@Conjunto Esperança
+55405@AC@16@Vila Ivonete@Vl Ivonete";
+
+    fn parse(options: &ParseOptions) -> LenientParse<Vec<Neighborhood>> {
+        let parser = EdneParser::from_utf8(SAMPLE_DATA.to_string());
+        parse_lenient(&parser, options)
+    }
+
+    #[test]
+    fn skips_invalid_lines() {
+        let LenientParse { records, errors, aborted } =
+            parse(&ParseOptions::new());
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].id, NeighborhoodId::new(55400));
+        assert_eq!(records[1].id, NeighborhoodId::new(55405));
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors[0].field_name(), Some("BAI_NU"));
+        assert_eq!(errors[1].field_name(), Some("UFE_SG"));
+        assert!(matches!(errors[2], ParseError::FieldCount { .. }));
+        assert!(aborted.is_none());
+    }
+
+    #[test]
+    fn ignore_policy_hides_errors() {
+        let options =
+            ParseOptions::new().field_policy("UFE_SG", FieldPolicy::Ignore);
+        let outcome = parse(&options);
+
+        assert_eq!(outcome.records.len(), 2);
+        assert_eq!(outcome.errors.len(), 2);
+        assert!(outcome.is_complete());
+    }
+
+    #[test]
+    fn abort_policy_stops_parsing() {
+        let options =
+            ParseOptions::new().field_policy("UFE_SG", FieldPolicy::Abort);
+        let outcome = parse(&options);
+
+        // The lines before the aborting one are kept.
+        assert_eq!(outcome.records.len(), 1);
+        assert_eq!(outcome.errors.len(), 1);
+        match outcome.aborted {
+            Some(ParseError::InvalidValue {
+                field_name, line_number, ..
+            }) => {
+                assert_eq!(field_name, "UFE_SG");
+                assert_eq!(line_number, 3);
+            }
+            e => panic!("Expected InvalidValue error, got {e:?}"),
+        }
+    }
+
+    #[test]
+    fn max_errors_stops_parsing() {
+        let options = ParseOptions::new().max_errors(1);
+        let outcome = parse(&options);
+
+        assert_eq!(outcome.records.len(), 1);
+        assert_eq!(outcome.errors.len(), 2);
+        match outcome.aborted {
+            Some(ParseError::TooManyErrors { max, line_number }) => {
+                assert_eq!(max, 1);
+                assert_eq!(line_number, 3);
+            }
+            e => panic!("Expected TooManyErrors error, got {e:?}"),
+        }
+
+        let options = ParseOptions::new().max_errors(3);
+        assert!(parse(&options).is_complete());
+    }
+}
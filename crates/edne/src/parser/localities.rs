@@ -18,10 +18,17 @@ use std::{collections::HashMap, str::FromStr};
 
 use crate::{
     models::{
-        Uf,
-        locality::{Locality, LocalityId, LocalitySituation, LocalityType},
+        IbgeMunicipalityCode, Uf,
+        locality::{
+            Locality, LocalityId, LocalityRef, LocalitySituation, LocalityType,
+        },
+    },
+    parser::{
+        base::{EdneParser, ParseError},
+        collection::{Collection, DeltaCollection},
+        helpers,
+        record_stream::{Record, RecordRef},
     },
-    parser::base::{EdneParser, ParseError},
 };
 
 /// Expected number of fields in a locality record.
@@ -62,48 +69,96 @@ impl Localities {
         self.0.insert(locality.id, locality)
     }
 
+    /// Removes a locality by ID, returning it if it was present.
+    pub fn remove(&mut self, id: &LocalityId) -> Option<Locality> {
+        self.0.remove(id)
+    }
+
     /// Returns an iterator over all localities.
     pub fn iter(&self) -> impl Iterator<Item = (&LocalityId, &Locality)> {
         self.0.iter()
     }
+}
 
-    /// Parses localities from ISO-8859-1 encoded bytes.
-    ///
-    /// # Arguments
-    ///
-    /// * `bytes` - Raw file content in ISO-8859-1 encoding
-    ///
-    /// # Errors
-    ///
-    /// Returns `ParseError` if encoding fails or any line has invalid data.
-    pub fn from_iso8859_1(bytes: &[u8]) -> Result<Self, ParseError> {
-        let parser = EdneParser::from_iso8859_1(bytes)?;
-        Self::parse_with_parser(&parser)
+impl Default for Localities {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    /// Parses localities from UTF-8 string (for testing).
-    pub fn from_utf8(content: String) -> Result<Self, ParseError> {
-        let parser = EdneParser::from_utf8(content);
-        Self::parse_with_parser(&parser)
+impl Collection for Localities {
+    type Record = Locality;
+    const ARCHIVE_ENTRY: &'static str = "LOG_LOCALIDADE.TXT";
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity(capacity)
     }
+}
 
-    /// Internal method to parse localities using a configured parser.
-    fn parse_with_parser(parser: &EdneParser) -> Result<Self, ParseError> {
-        let lines: Vec<_> = parser.lines().collect();
-        let mut localities = Self::with_capacity(lines.len());
+impl DeltaCollection for Localities {
+    type Key = LocalityId;
+    const OPERATION_FIELD: &'static str = "LOC_OPERACAO";
+
+    fn key(record: &Locality) -> LocalityId {
+        record.id
+    }
 
-        for (line_number, line) in lines {
-            let locality = parse_locality_line(parser, line, line_number)?;
-            localities.insert(locality);
+    fn insert(&mut self, record: Locality) -> Option<Locality> {
+        self.0.insert(record.id, record)
+    }
+
+    fn remove(&mut self, key: &LocalityId) -> Option<Locality> {
+        self.0.remove(key)
+    }
+}
+
+impl Extend<Locality> for Localities {
+    fn extend<I: IntoIterator<Item = Locality>>(&mut self, iter: I) {
+        for record in iter {
+            self.insert(record);
         }
+    }
+}
 
-        Ok(localities)
+impl FromIterator<Locality> for Localities {
+    fn from_iter<I: IntoIterator<Item = Locality>>(iter: I) -> Self {
+        let mut records = Self::new();
+        records.extend(iter);
+        records
     }
 }
 
-impl Default for Localities {
-    fn default() -> Self {
-        Self::new()
+impl Record for Locality {
+    const FIELDS: &'static [&'static str] = &[
+        "LOC_NU",
+        "UFE_SG",
+        "LOC_NO",
+        "CEP",
+        "LOC_IN_SIT",
+        "LOC_IN_TIPO_LOC",
+        "LOC_NU_SUB",
+        "LOC_NO_ABREV",
+        "MUN_NU",
+    ];
+
+    fn parse_line(
+        parser: &EdneParser,
+        line: &str,
+        line_number: usize,
+    ) -> Result<Self, ParseError> {
+        parse_locality_line(parser, line, line_number)
+    }
+}
+
+impl<'a> RecordRef<'a> for LocalityRef<'a> {
+    type Owned = Locality;
+
+    fn parse_line(
+        parser: &EdneParser,
+        line: &'a str,
+        line_number: usize,
+    ) -> Result<Self, ParseError> {
+        parse_locality_ref_line(parser, line, line_number)
     }
 }
 
@@ -124,74 +179,107 @@ fn parse_locality_line(
     line: &str,
     line_number: usize,
 ) -> Result<Locality, ParseError> {
+    parse_locality_ref_line(parser, line, line_number).map(|r| r.to_owned())
+}
+
+/// Parses a single line into a borrowed `LocalityRef`, without allocating its
+/// text fields. The field order is the same as [`parse_locality_line`].
+fn parse_locality_ref_line<'a>(
+    parser: &EdneParser,
+    line: &'a str,
+    line_number: usize,
+) -> Result<LocalityRef<'a>, ParseError> {
     let fields =
         parser.parse_line_checked(line, LOCALITY_FIELD_COUNT, line_number)?;
 
     // Parse required fields
-    let id_str = EdneParser::required_field(fields[0], "LOC_NU", line_number)?;
-    let id = LocalityId::from_str(&id_str).map_err(|e| {
+    let id_str = EdneParser::required_str(fields[0], "LOC_NU", line_number)?;
+    let id = LocalityId::from_str(id_str).map_err(|e| {
         ParseError::InvalidValue {
             field_name: "LOC_NU",
-            value: id_str,
+            value: id_str.to_string(),
             reason: e.to_string(),
+            source: Some(e.into()),
             line_number,
+            span: None,
         }
     })?;
 
-    let uf_str = EdneParser::required_field(fields[1], "UFE_SG", line_number)?;
-    let uf = Uf::from_str(&uf_str).map_err(|e| ParseError::InvalidValue {
+    let uf_str = EdneParser::required_str(fields[1], "UFE_SG", line_number)?;
+    let uf = Uf::from_str(uf_str).map_err(|e| ParseError::InvalidValue {
         field_name: "UFE_SG",
-        value: uf_str,
+        value: uf_str.to_string(),
         reason: e.to_string(),
+        source: Some(e.into()),
         line_number,
+        span: None,
     })?;
 
-    let name = EdneParser::required_field(fields[2], "LOC_NO", line_number)?;
+    let name = EdneParser::required_str(fields[2], "LOC_NO", line_number)?;
 
     let situation_str =
-        EdneParser::required_field(fields[4], "LOC_IN_SIT", line_number)?;
+        EdneParser::required_str(fields[4], "LOC_IN_SIT", line_number)?;
     let situation =
-        LocalitySituation::from_str(&situation_str).map_err(|e| {
+        LocalitySituation::from_str(situation_str).map_err(|e| {
             ParseError::InvalidValue {
                 field_name: "LOC_IN_SIT",
-                value: situation_str,
+                value: situation_str.to_string(),
                 reason: e.to_string(),
+                source: Some(e.into()),
                 line_number,
+                span: None,
             }
         })?;
 
     let type_str =
-        EdneParser::required_field(fields[5], "LOC_IN_TIPO_LOC", line_number)?;
-    let locality_type = LocalityType::from_str(&type_str).map_err(|e| {
+        EdneParser::required_str(fields[5], "LOC_IN_TIPO_LOC", line_number)?;
+    let locality_type = LocalityType::from_str(type_str).map_err(|e| {
         ParseError::InvalidValue {
             field_name: "LOC_IN_TIPO_LOC",
-            value: type_str,
+            value: type_str.to_string(),
             reason: e.to_string(),
+            source: Some(e.into()),
             line_number,
+            span: None,
         }
     })?;
 
     // Parse optional fields
-    let cep = EdneParser::optional_field(fields[3]);
+    let cep = helpers::optional_cep(fields[3], "CEP", line_number)?;
 
     let subordinate_to =
-        if let Some(sub_id_str) = EdneParser::optional_field(fields[6]) {
-            Some(LocalityId::from_str(&sub_id_str).map_err(|e| {
+        if let Some(sub_id_str) = EdneParser::optional_str(fields[6]) {
+            Some(LocalityId::from_str(sub_id_str).map_err(|e| {
                 ParseError::InvalidValue {
                     field_name: "LOC_NU_SUB",
-                    value: sub_id_str,
+                    value: sub_id_str.to_string(),
                     reason: e.to_string(),
+                    source: Some(e.into()),
                     line_number,
+                    span: None,
                 }
             })?)
         } else {
             None
         };
 
-    let abbreviated_name = EdneParser::optional_field(fields[7]);
-    let ibge_code = EdneParser::optional_field(fields[8]);
+    let abbreviated_name = EdneParser::optional_str(fields[7]);
+    let ibge_code = EdneParser::optional_str(fields[8])
+        .map(|code_str| {
+            IbgeMunicipalityCode::from_str(code_str).map_err(|e| {
+                ParseError::InvalidValue {
+                    field_name: "MUN_NU",
+                    value: code_str.to_string(),
+                    reason: e.to_string(),
+                    source: Some(e.into()),
+                    line_number,
+                    span: None,
+                }
+            })
+        })
+        .transpose()?;
 
-    Ok(Locality {
+    Ok(LocalityRef {
         id,
         uf,
         name,
@@ -207,6 +295,10 @@ fn parse_locality_line(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        error::{IbgeMunicipalityCodeError, ValueError},
+        models::Cep,
+    };
 
     const SAMPLE_DATA: &str = "\
 15321@AC@Terra Indgena Mamoadate@69939810@0@P@2@Terra Ind Mamoadate@
@@ -232,7 +324,7 @@ mod tests {
         assert_eq!(locality.id, id);
         assert_eq!(locality.uf, Uf::AC);
         assert_eq!(locality.name, "Plcido de Castro");
-        assert_eq!(locality.cep, Some("69928000".to_string()));
+        assert_eq!(locality.cep, Some(Cep::new(69928000)));
         assert_eq!(locality.situation, LocalitySituation::NotCoded);
         assert_eq!(locality.locality_type, LocalityType::Municipality);
         assert_eq!(locality.subordinate_to, None);
@@ -240,7 +332,7 @@ mod tests {
             locality.abbreviated_name,
             Some("Plcido Castro".to_string())
         );
-        assert_eq!(locality.ibge_code, Some("1200385".to_string()));
+        assert_eq!(locality.ibge_code.map(|code| code.get()), Some(1200385));
     }
 
     #[test]
@@ -258,7 +350,9 @@ mod tests {
         assert_eq!(locality.locality_type, LocalityType::Municipality);
         assert_eq!(locality.subordinate_to, None);
         assert_eq!(locality.abbreviated_name, Some("Rio Branco".to_string()));
-        assert_eq!(locality.ibge_code, Some("1200401".to_string()));
+        let ibge_code = locality.ibge_code.unwrap();
+        assert_eq!(ibge_code.get(), 1200401);
+        assert_eq!(ibge_code.uf(), Uf::AC);
     }
 
     #[test]
@@ -286,6 +380,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_invalid_ibge_code() {
+        let invalid = "16@AC@Rio Branco@@1@M@@Rio Branco@1200402";
+        let err = Localities::from_utf8(invalid.to_string()).unwrap_err();
+        match &err {
+            ParseError::InvalidValue { field_name, value, .. } => {
+                assert_eq!(*field_name, "MUN_NU");
+                assert_eq!(value, "1200402");
+            }
+            e => panic!("Expected InvalidValue error, got {e:?}"),
+        }
+        assert_eq!(
+            err.value_error(),
+            Some(&ValueError::IbgeMunicipalityCode(
+                IbgeMunicipalityCodeError::InvalidCheckDigit(1200402)
+            ))
+        );
+    }
+
     #[test]
     fn parse_invalid_id() {
         let invalid = "abc@AC@Terra Indgena@69939810@0@P@2@Terra Ind@@";
@@ -342,4 +455,44 @@ mod tests {
         let result = localities.get(&LocalityId::new(99999));
         assert!(result.is_none());
     }
+
+    const DELTA_DATA: &str = "\
+15321@AC@Nome Novo@69939810@0@P@2@Terra Ind Mamoadate@@UPD
+13@AC@Plcido de Castro@69928000@0@M@@Plcido Castro@1200385@DEL
+999999@AC@Terra Indgena Mamoadate@69939810@0@P@2@Terra Ind Mamoadate@@INS";
+
+    #[test]
+    fn apply_delta_to_base() {
+        let mut localities =
+            Localities::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        let before = localities.len();
+        let deltas =
+            Localities::deltas_from_utf8(DELTA_DATA.to_string()).unwrap();
+        assert_eq!(deltas.len(), 3);
+
+        for delta in deltas {
+            localities.apply_delta(delta);
+        }
+
+        assert_eq!(localities.len(), before);
+        assert_eq!(
+            localities.get(&LocalityId::new(15321)).unwrap().name,
+            "Nome Novo"
+        );
+        assert!(localities.get(&LocalityId::new(13)).is_none());
+        assert!(localities.get(&LocalityId::new(999999)).is_some());
+    }
+
+    #[test]
+    fn parse_delta_invalid_operation() {
+        let invalid =
+            "13@AC@Plcido de Castro@69928000@0@M@@Plcido Castro@1200385@XXX";
+        let result = Localities::deltas_from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::InvalidValue { field_name, .. } => {
+                assert_eq!(field_name, "LOC_OPERACAO");
+            }
+            _ => panic!("Expected InvalidValue error"),
+        }
+    }
 }
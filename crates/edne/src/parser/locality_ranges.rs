@@ -0,0 +1,263 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+use std::{collections::HashMap, str::FromStr};
+
+use crate::{
+    models::{
+        Cep, LocalityId,
+        locality_cep_range::{LocalityCepRange, LocalityRangeType},
+    },
+    parser::{
+        base::{EdneParser, ParseError},
+        collection::Collection,
+        helpers,
+        record_stream::Record,
+    },
+};
+
+/// Expected number of fields in a locality CEP range record.
+const LOCALITY_CEP_RANGE_FIELD_COUNT: usize = 4;
+
+/// Collection of locality CEP ranges indexed by their locality ID.
+#[derive(Debug, Clone)]
+pub struct LocalityCepRanges(HashMap<LocalityId, Vec<LocalityCepRange>>);
+
+impl LocalityCepRanges {
+    /// Creates a new empty collection.
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Creates a collection with pre-allocated capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(HashMap::with_capacity(capacity))
+    }
+
+    /// Returns the number of ranges.
+    pub fn len(&self) -> usize {
+        self.0.values().map(Vec::len).sum()
+    }
+
+    /// Returns `true` if the collection is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Gets all ranges of a locality.
+    pub fn get(&self, id: &LocalityId) -> Option<&[LocalityCepRange]> {
+        self.0.get(id).map(Vec::as_slice)
+    }
+
+    /// Inserts a range into the collection.
+    pub fn insert(&mut self, range: LocalityCepRange) {
+        self.0.entry(range.locality_id).or_default().push(range);
+    }
+
+    /// Returns an iterator over all ranges.
+    pub fn iter(&self) -> impl Iterator<Item = &LocalityCepRange> {
+        self.0.values().flatten()
+    }
+
+    /// Returns the ID of the locality whose ranges contain `cep`.
+    pub fn locality_for_cep(&self, cep: Cep) -> Option<LocalityId> {
+        self.iter()
+            .find(|range| range.contains(cep))
+            .map(|range| range.locality_id)
+    }
+}
+
+impl Default for LocalityCepRanges {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collection for LocalityCepRanges {
+    type Record = LocalityCepRange;
+    const ARCHIVE_ENTRY: &'static str = "LOG_FAIXA_LOCALIDADE.TXT";
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity(capacity)
+    }
+}
+
+impl Extend<LocalityCepRange> for LocalityCepRanges {
+    fn extend<I: IntoIterator<Item = LocalityCepRange>>(&mut self, iter: I) {
+        for record in iter {
+            self.insert(record);
+        }
+    }
+}
+
+impl FromIterator<LocalityCepRange> for LocalityCepRanges {
+    fn from_iter<I: IntoIterator<Item = LocalityCepRange>>(iter: I) -> Self {
+        let mut records = Self::new();
+        records.extend(iter);
+        records
+    }
+}
+
+impl Record for LocalityCepRange {
+    const FIELDS: &'static [&'static str] =
+        &["LOC_NU", "LOC_CEP_INI", "LOC_CEP_FIM", "LOC_TIPO_FAIXA"];
+
+    fn parse_line(
+        parser: &EdneParser,
+        line: &str,
+        line_number: usize,
+    ) -> Result<Self, ParseError> {
+        parse_locality_cep_range_line(parser, line, line_number)
+    }
+}
+
+/// Parses a single LOG_FAIXA_LOCALIDADE line into a `LocalityCepRange`.
+///
+/// # Field order (4 fields):
+/// 1. LOC_NU - Locality ID
+/// 2. LOC_CEP_INI - First CEP of the range
+/// 3. LOC_CEP_FIM - Last CEP of the range
+/// 4. LOC_TIPO_FAIXA - Range type
+fn parse_locality_cep_range_line(
+    parser: &EdneParser,
+    line: &str,
+    line_number: usize,
+) -> Result<LocalityCepRange, ParseError> {
+    let fields = parser.parse_line_checked(
+        line,
+        LOCALITY_CEP_RANGE_FIELD_COUNT,
+        line_number,
+    )?;
+
+    let id_str = EdneParser::required_field(fields[0], "LOC_NU", line_number)?;
+    let locality_id = LocalityId::from_str(&id_str).map_err(|e| {
+        ParseError::InvalidValue {
+            field_name: "LOC_NU",
+            value: id_str,
+            reason: e.to_string(),
+            source: Some(e.into()),
+            line_number,
+            span: None,
+        }
+    })?;
+
+    let (cep_start, cep_end) = helpers::required_cep_range(
+        (fields[1], "LOC_CEP_INI"),
+        (fields[2], "LOC_CEP_FIM"),
+        line_number,
+    )?;
+
+    let type_str =
+        EdneParser::required_field(fields[3], "LOC_TIPO_FAIXA", line_number)?;
+    let range_type = LocalityRangeType::from_str(&type_str).map_err(|e| {
+        ParseError::InvalidValue {
+            field_name: "LOC_TIPO_FAIXA",
+            value: type_str,
+            reason: e.to_string(),
+            source: Some(e.into()),
+            line_number,
+            span: None,
+        }
+    })?;
+
+    Ok(LocalityCepRange { locality_id, cep_start, cep_end, range_type })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_DATA: &str = "\
+1@69945000@69949999@T
+2@69932000@69933999@T
+13@69928000@69929999@T
+16@69900001@69923999@T
+16@69900001@69923999@C
+21@69970000@69974999@T";
+
+    #[test]
+    fn parse_sample_data() {
+        let ranges =
+            LocalityCepRanges::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        assert_eq!(ranges.len(), 6);
+    }
+
+    #[test]
+    fn parse_locality_with_several_ranges() {
+        let ranges =
+            LocalityCepRanges::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        let rio_branco = ranges.get(&LocalityId::new(16)).unwrap();
+
+        assert_eq!(rio_branco.len(), 2);
+        assert_eq!(rio_branco[0].cep_start, Cep::new(69900001));
+        assert_eq!(rio_branco[0].cep_end, Cep::new(69923999));
+        assert_eq!(rio_branco[0].range_type, LocalityRangeType::Total);
+        assert_eq!(rio_branco[1].range_type, LocalityRangeType::Exception);
+    }
+
+    #[test]
+    fn locality_for_cep_found() {
+        let ranges =
+            LocalityCepRanges::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        assert_eq!(
+            ranges.locality_for_cep(Cep::new(69918703)),
+            Some(LocalityId::new(16))
+        );
+        assert_eq!(
+            ranges.locality_for_cep(Cep::new(69928000)),
+            Some(LocalityId::new(13))
+        );
+    }
+
+    #[test]
+    fn locality_for_cep_not_found() {
+        let ranges =
+            LocalityCepRanges::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        assert_eq!(ranges.locality_for_cep(Cep::new(1310100)), None);
+    }
+
+    #[test]
+    fn parse_invalid_field_count() {
+        let invalid = "16@69900001@69923999";
+        let result = LocalityCepRanges::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::FieldCount { expected, got, .. } => {
+                assert_eq!(expected, 4);
+                assert_eq!(got, 3);
+            }
+            _ => panic!("Expected FieldCount error"),
+        }
+    }
+
+    #[test]
+    fn parse_invalid_locality_id() {
+        let invalid = "abc@69900001@69923999@T";
+        let result = LocalityCepRanges::from_utf8(invalid.to_string());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_invalid_range_type() {
+        let invalid = "16@69900001@69923999@X";
+        let result = LocalityCepRanges::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::InvalidValue { field_name, .. } => {
+                assert_eq!(field_name, "LOC_TIPO_FAIXA");
+            }
+            _ => panic!("Expected InvalidValue error"),
+        }
+    }
+}
@@ -0,0 +1,237 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+use std::{collections::HashMap, str::FromStr};
+
+use crate::{
+    models::{LocalityId, name_variant::LocalityNameVariant},
+    parser::{
+        base::{EdneParser, ParseError},
+        collection::Collection,
+        record_stream::Record,
+    },
+};
+
+/// Expected number of fields in a locality name variant record.
+const LOCALITY_VARIANT_FIELD_COUNT: usize = 3;
+
+/// Collection of locality name variants indexed by their locality ID.
+#[derive(Debug, Clone)]
+pub struct LocalityNameVariants(HashMap<LocalityId, Vec<LocalityNameVariant>>);
+
+impl LocalityNameVariants {
+    /// Creates a new empty collection.
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Creates a collection with pre-allocated capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(HashMap::with_capacity(capacity))
+    }
+
+    /// Returns the number of variants.
+    pub fn len(&self) -> usize {
+        self.0.values().map(Vec::len).sum()
+    }
+
+    /// Returns `true` if the collection is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Gets all variants of a locality.
+    pub fn get(&self, id: &LocalityId) -> Option<&[LocalityNameVariant]> {
+        self.0.get(id).map(Vec::as_slice)
+    }
+
+    /// Inserts a variant into the collection.
+    pub fn insert(&mut self, variant: LocalityNameVariant) {
+        self.0.entry(variant.locality_id).or_default().push(variant);
+    }
+
+    /// Returns an iterator over all variants.
+    pub fn iter(&self) -> impl Iterator<Item = &LocalityNameVariant> {
+        self.0.values().flatten()
+    }
+
+    /// Returns an iterator over the variants whose name matches `name`,
+    /// ignoring case.
+    pub fn find_by_name(
+        &self,
+        name: &str,
+    ) -> impl Iterator<Item = &LocalityNameVariant> {
+        let name = name.trim().to_lowercase();
+        self.iter().filter(move |variant| variant.name.to_lowercase() == name)
+    }
+}
+
+impl Default for LocalityNameVariants {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collection for LocalityNameVariants {
+    type Record = LocalityNameVariant;
+    const ARCHIVE_ENTRY: &'static str = "LOG_VAR_LOC.TXT";
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity(capacity)
+    }
+}
+
+impl Extend<LocalityNameVariant> for LocalityNameVariants {
+    fn extend<I: IntoIterator<Item = LocalityNameVariant>>(
+        &mut self,
+        iter: I,
+    ) {
+        for record in iter {
+            self.insert(record);
+        }
+    }
+}
+
+impl FromIterator<LocalityNameVariant> for LocalityNameVariants {
+    fn from_iter<I: IntoIterator<Item = LocalityNameVariant>>(
+        iter: I,
+    ) -> Self {
+        let mut records = Self::new();
+        records.extend(iter);
+        records
+    }
+}
+
+impl Record for LocalityNameVariant {
+    const FIELDS: &'static [&'static str] = &["LOC_NU", "VAL_NU", "VAL_TX"];
+
+    fn parse_line(
+        parser: &EdneParser,
+        line: &str,
+        line_number: usize,
+    ) -> Result<Self, ParseError> {
+        parse_locality_variant_line(parser, line, line_number)
+    }
+}
+
+/// Parses a single LOG_VAR_LOC line into a `LocalityNameVariant` struct.
+///
+/// # Field order (3 fields):
+/// 1. LOC_NU - Locality ID
+/// 2. VAL_NU - Variant sequence number
+/// 3. VAL_TX - Variant name
+fn parse_locality_variant_line(
+    parser: &EdneParser,
+    line: &str,
+    line_number: usize,
+) -> Result<LocalityNameVariant, ParseError> {
+    let fields = parser.parse_line_checked(
+        line,
+        LOCALITY_VARIANT_FIELD_COUNT,
+        line_number,
+    )?;
+
+    let id_str = EdneParser::required_field(fields[0], "LOC_NU", line_number)?;
+    let locality_id = LocalityId::from_str(&id_str).map_err(|e| {
+        ParseError::InvalidValue {
+            field_name: "LOC_NU",
+            value: id_str,
+            reason: e.to_string(),
+            source: Some(e.into()),
+            line_number,
+            span: None,
+        }
+    })?;
+
+    let sequence = EdneParser::parse_number(fields[1], "VAL_NU", line_number)?;
+    let name = EdneParser::required_field(fields[2], "VAL_TX", line_number)?;
+
+    Ok(LocalityNameVariant { locality_id, sequence, name })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_DATA: &str = "\
+16@1@Rio Branco do Acre
+9668@1@Sampa
+9668@2@São Paulo de Piratininga
+7043@1@Rio";
+
+    #[test]
+    fn parse_sample_data() {
+        let variants =
+            LocalityNameVariants::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        assert_eq!(variants.len(), 4);
+    }
+
+    #[test]
+    fn parse_locality_with_several_variants() {
+        let variants =
+            LocalityNameVariants::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        let sao_paulo = variants.get(&LocalityId::new(9668)).unwrap();
+
+        assert_eq!(sao_paulo.len(), 2);
+        assert_eq!(sao_paulo[0].sequence, 1);
+        assert_eq!(sao_paulo[0].name, "Sampa");
+        assert_eq!(sao_paulo[1].sequence, 2);
+        assert_eq!(sao_paulo[1].name, "São Paulo de Piratininga");
+    }
+
+    #[test]
+    fn find_by_name_ignores_case() {
+        let variants =
+            LocalityNameVariants::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        let found: Vec<_> = variants.find_by_name(" sampa ").collect();
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].locality_id, LocalityId::new(9668));
+        assert_eq!(variants.find_by_name("Curitiba").count(), 0);
+    }
+
+    #[test]
+    fn parse_invalid_field_count() {
+        let invalid = "9668@Sampa";
+        let result = LocalityNameVariants::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::FieldCount { expected, got, .. } => {
+                assert_eq!(expected, 3);
+                assert_eq!(got, 2);
+            }
+            _ => panic!("Expected FieldCount error"),
+        }
+    }
+
+    #[test]
+    fn parse_invalid_sequence() {
+        let invalid = "9668@a@Sampa";
+        let result = LocalityNameVariants::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::InvalidNumber { field_name, .. } => {
+                assert_eq!(field_name, "VAL_NU");
+            }
+            _ => panic!("Expected InvalidNumber error"),
+        }
+    }
+
+    #[test]
+    fn parse_empty_name() {
+        let invalid = "9668@1@";
+        let result = LocalityNameVariants::from_utf8(invalid.to_string());
+        assert!(result.is_err());
+    }
+}
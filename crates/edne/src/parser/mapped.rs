@@ -0,0 +1,127 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+//! Memory-mapped file input.
+//!
+//! `from_path` maps the file and decodes it one line at a time, so the
+//! whole decoded content is never held in memory at once.
+//!
+//! The mapping reads the file directly: it must not be truncated or
+//! modified while it is being parsed. Truncating a mapped file makes reads
+//! past its new end fault (SIGBUS on Unix), which is undefined behaviour.
+
+use std::{fs::File, path::Path};
+
+use memmap2::Mmap;
+
+use crate::parser::{
+    base::ParseError,
+    encoding::Encoding,
+    record_stream::{Record, stream},
+};
+
+/// Memory-maps the file at `path` and passes each parsed record to
+/// `insert`.
+///
+/// `Encoding::Auto` is detected once from the whole file.
+///
+/// The caller must ensure the file is not truncated or modified until this
+/// function returns.
+///
+/// # Errors
+///
+/// Returns `ParseError::Io` if the file cannot be opened or mapped, or the
+/// first `ParseError` of an invalid line.
+pub(crate) fn parse_path<T, F>(
+    path: &Path,
+    encoding: Encoding,
+    mut insert: F,
+) -> Result<(), ParseError>
+where
+    T: Record,
+    F: FnMut(T),
+{
+    let io_error =
+        |source| ParseError::Io { path: Some(path.to_path_buf()), source };
+
+    let file = File::open(path).map_err(io_error)?;
+    // SAFETY: the file must not be truncated or modified while it is
+    // mapped. Truncation makes reads of the mapping fault and modification
+    // breaks the immutability of `&mmap[..]`; both are undefined behaviour.
+    // This cannot be checked here, so it is part of the documented contract
+    // of `Collection::from_path`. The mapping is dropped before returning.
+    let mmap = unsafe { Mmap::map(&file) }.map_err(io_error)?;
+
+    let encoding = match encoding {
+        Encoding::Auto => Encoding::detect(&mmap),
+        other => other,
+    };
+    let file_name = path.display().to_string();
+    let records = stream::<T, _>(&mmap[..])
+        .with_encoding(encoding)
+        .with_file_name(file_name);
+    for record in records {
+        insert(record?);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Cpc, CpcId};
+    use std::{env, fs, process};
+
+    #[test]
+    fn parse_path_reads_mapped_file() {
+        let path =
+            env::temp_dir().join(format!("edne-mapped-{}.txt", process::id()));
+        fs::write(
+            &path,
+            b"1285@AL@158@Conjunto Mutir\xe3o@Quadra 1@57100990\n",
+        )
+        .unwrap();
+
+        let mut cpcs: Vec<Cpc> = Vec::new();
+        let result = parse_path(&path, Encoding::Auto, |cpc| cpcs.push(cpc));
+        fs::remove_file(&path).unwrap();
+
+        result.unwrap();
+        assert_eq!(cpcs.len(), 1);
+        assert_eq!(cpcs[0].id, CpcId::new(1285));
+        assert_eq!(cpcs[0].name, "Conjunto Mutirão");
+    }
+
+    #[test]
+    fn parse_path_missing_file() {
+        let result = parse_path::<Cpc, _>(
+            Path::new("/nonexistent/LOG_CPC.TXT"),
+            Encoding::Latin1,
+            |_| {},
+        );
+        match result.unwrap_err() {
+            ParseError::Io { path, source } => {
+                assert_eq!(
+                    path.unwrap(),
+                    Path::new("/nonexistent/LOG_CPC.TXT")
+                );
+                assert_eq!(source.kind(), std::io::ErrorKind::NotFound);
+            }
+            err => panic!("Expected Io error, got {err:?}"),
+        }
+    }
+}
@@ -14,11 +14,38 @@
 // OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 //
 
+pub mod address_variants;
 pub mod addresses;
+pub mod archive;
+#[cfg(feature = "async")]
+pub mod async_stream;
 pub mod base;
 pub mod big_users;
+pub mod collection;
+pub mod countries;
+pub mod cpc_ranges;
 pub mod cpcs;
+pub mod delta;
+pub mod encoding;
 pub mod helpers;
+pub mod lenient;
 pub mod localities;
+pub mod locality_ranges;
+pub mod locality_variants;
+pub mod mapped;
+pub mod neighborhood_ranges;
+pub mod neighborhood_variants;
 pub mod neighborhoods;
+pub mod number_sections;
+pub mod operational_unit_ranges;
 pub mod operational_units;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+pub mod record_stream;
+pub mod span;
+pub mod uf_ranges;
+
+pub use collection::{Collection, DeltaCollection};
+pub use record_stream::{
+    Record, RecordRef, RecordStream, records_ref, stream, stream_with_layout,
+};
@@ -0,0 +1,252 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+use std::{collections::HashMap, str::FromStr};
+
+use crate::{
+    models::{
+        Cep, NeighborhoodId, neighborhood_cep_range::NeighborhoodCepRange,
+    },
+    parser::{
+        base::{EdneParser, ParseError},
+        collection::Collection,
+        helpers,
+        record_stream::Record,
+    },
+};
+
+/// Expected number of fields in a neighborhood CEP range record.
+const NEIGHBORHOOD_CEP_RANGE_FIELD_COUNT: usize = 3;
+
+/// Collection of neighborhood CEP ranges indexed by their neighborhood ID.
+#[derive(Debug, Clone)]
+pub struct NeighborhoodCepRanges(
+    HashMap<NeighborhoodId, Vec<NeighborhoodCepRange>>,
+);
+
+impl NeighborhoodCepRanges {
+    /// Creates a new empty collection.
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Creates a collection with pre-allocated capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(HashMap::with_capacity(capacity))
+    }
+
+    /// Returns the number of ranges.
+    pub fn len(&self) -> usize {
+        self.0.values().map(Vec::len).sum()
+    }
+
+    /// Returns `true` if the collection is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Gets all ranges of a neighborhood.
+    pub fn get(&self, id: &NeighborhoodId) -> Option<&[NeighborhoodCepRange]> {
+        self.0.get(id).map(Vec::as_slice)
+    }
+
+    /// Inserts a range into the collection.
+    pub fn insert(&mut self, range: NeighborhoodCepRange) {
+        self.0.entry(range.neighborhood_id).or_default().push(range);
+    }
+
+    /// Returns an iterator over all ranges.
+    pub fn iter(&self) -> impl Iterator<Item = &NeighborhoodCepRange> {
+        self.0.values().flatten()
+    }
+
+    /// Returns the ID of the neighborhood whose ranges contain `cep`.
+    pub fn neighborhood_for_cep(&self, cep: Cep) -> Option<NeighborhoodId> {
+        self.iter()
+            .find(|range| range.contains(cep))
+            .map(|range| range.neighborhood_id)
+    }
+}
+
+impl Default for NeighborhoodCepRanges {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collection for NeighborhoodCepRanges {
+    type Record = NeighborhoodCepRange;
+    const ARCHIVE_ENTRY: &'static str = "LOG_FAIXA_BAIRRO.TXT";
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity(capacity)
+    }
+}
+
+impl Extend<NeighborhoodCepRange> for NeighborhoodCepRanges {
+    fn extend<I: IntoIterator<Item = NeighborhoodCepRange>>(
+        &mut self,
+        iter: I,
+    ) {
+        for record in iter {
+            self.insert(record);
+        }
+    }
+}
+
+impl FromIterator<NeighborhoodCepRange> for NeighborhoodCepRanges {
+    fn from_iter<I: IntoIterator<Item = NeighborhoodCepRange>>(
+        iter: I,
+    ) -> Self {
+        let mut records = Self::new();
+        records.extend(iter);
+        records
+    }
+}
+
+impl Record for NeighborhoodCepRange {
+    const FIELDS: &'static [&'static str] =
+        &["BAI_NU", "FCB_CEP_INI", "FCB_CEP_FIM"];
+
+    fn parse_line(
+        parser: &EdneParser,
+        line: &str,
+        line_number: usize,
+    ) -> Result<Self, ParseError> {
+        parse_neighborhood_cep_range_line(parser, line, line_number)
+    }
+}
+
+/// Parses a single LOG_FAIXA_BAIRRO line into a `NeighborhoodCepRange`.
+///
+/// # Field order (3 fields):
+/// 1. BAI_NU - Neighborhood ID
+/// 2. FCB_CEP_INI - First CEP of the range
+/// 3. FCB_CEP_FIM - Last CEP of the range
+fn parse_neighborhood_cep_range_line(
+    parser: &EdneParser,
+    line: &str,
+    line_number: usize,
+) -> Result<NeighborhoodCepRange, ParseError> {
+    let fields = parser.parse_line_checked(
+        line,
+        NEIGHBORHOOD_CEP_RANGE_FIELD_COUNT,
+        line_number,
+    )?;
+
+    let id_str = EdneParser::required_field(fields[0], "BAI_NU", line_number)?;
+    let neighborhood_id = NeighborhoodId::from_str(&id_str).map_err(|e| {
+        ParseError::InvalidValue {
+            field_name: "BAI_NU",
+            value: id_str,
+            reason: e.to_string(),
+            source: Some(e.into()),
+            line_number,
+            span: None,
+        }
+    })?;
+
+    let (cep_start, cep_end) = helpers::required_cep_range(
+        (fields[1], "FCB_CEP_INI"),
+        (fields[2], "FCB_CEP_FIM"),
+        line_number,
+    )?;
+
+    Ok(NeighborhoodCepRange { neighborhood_id, cep_start, cep_end })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_DATA: &str = "\
+17@69900001@69900999
+30@69915500@69915599
+55400@69915000@69915299
+55400@69915400@69915499
+55480@69922200@69922299";
+
+    #[test]
+    fn parse_sample_data() {
+        let ranges =
+            NeighborhoodCepRanges::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        assert_eq!(ranges.len(), 5);
+    }
+
+    #[test]
+    fn parse_neighborhood_with_several_ranges() {
+        let ranges =
+            NeighborhoodCepRanges::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        let jaguar = ranges.get(&NeighborhoodId::new(55400)).unwrap();
+
+        assert_eq!(jaguar.len(), 2);
+        assert_eq!(jaguar[0].cep_start, Cep::new(69915000));
+        assert_eq!(jaguar[1].cep_end, Cep::new(69915499));
+    }
+
+    #[test]
+    fn neighborhood_for_cep_found() {
+        let ranges =
+            NeighborhoodCepRanges::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        assert_eq!(
+            ranges.neighborhood_for_cep(Cep::new(69922227)),
+            Some(NeighborhoodId::new(55480))
+        );
+        assert_eq!(
+            ranges.neighborhood_for_cep(Cep::new(69915450)),
+            Some(NeighborhoodId::new(55400))
+        );
+    }
+
+    #[test]
+    fn neighborhood_for_cep_not_found() {
+        let ranges =
+            NeighborhoodCepRanges::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        assert_eq!(ranges.neighborhood_for_cep(Cep::new(69915350)), None);
+    }
+
+    #[test]
+    fn parse_invalid_field_count() {
+        let invalid = "55400@69915000";
+        let result = NeighborhoodCepRanges::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::FieldCount { expected, got, .. } => {
+                assert_eq!(expected, 3);
+                assert_eq!(got, 2);
+            }
+            _ => panic!("Expected FieldCount error"),
+        }
+    }
+
+    #[test]
+    fn parse_invalid_neighborhood_id() {
+        let invalid = "0@69915000@69915299";
+        let result = NeighborhoodCepRanges::from_utf8(invalid.to_string());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_empty_cep() {
+        let invalid = "55400@@69915299";
+        let result = NeighborhoodCepRanges::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::EmptyField { field_name, .. } => {
+                assert_eq!(field_name, "FCB_CEP_INI");
+            }
+            _ => panic!("Expected EmptyField error"),
+        }
+    }
+}
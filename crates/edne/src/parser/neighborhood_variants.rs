@@ -0,0 +1,230 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+use std::{collections::HashMap, str::FromStr};
+
+use crate::{
+    models::{NeighborhoodId, name_variant::NeighborhoodNameVariant},
+    parser::{
+        base::{EdneParser, ParseError},
+        collection::Collection,
+        record_stream::Record,
+    },
+};
+
+/// Expected number of fields in a neighborhood name variant record.
+const NEIGHBORHOOD_VARIANT_FIELD_COUNT: usize = 3;
+
+/// Collection of neighborhood name variants indexed by their neighborhood ID.
+#[derive(Debug, Clone)]
+pub struct NeighborhoodNameVariants(
+    HashMap<NeighborhoodId, Vec<NeighborhoodNameVariant>>,
+);
+
+impl NeighborhoodNameVariants {
+    /// Creates a new empty collection.
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Creates a collection with pre-allocated capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(HashMap::with_capacity(capacity))
+    }
+
+    /// Returns the number of variants.
+    pub fn len(&self) -> usize {
+        self.0.values().map(Vec::len).sum()
+    }
+
+    /// Returns `true` if the collection is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Gets all variants of a neighborhood.
+    pub fn get(
+        &self,
+        id: &NeighborhoodId,
+    ) -> Option<&[NeighborhoodNameVariant]> {
+        self.0.get(id).map(Vec::as_slice)
+    }
+
+    /// Inserts a variant into the collection.
+    pub fn insert(&mut self, variant: NeighborhoodNameVariant) {
+        self.0.entry(variant.neighborhood_id).or_default().push(variant);
+    }
+
+    /// Returns an iterator over all variants.
+    pub fn iter(&self) -> impl Iterator<Item = &NeighborhoodNameVariant> {
+        self.0.values().flatten()
+    }
+
+    /// Returns an iterator over the variants whose name matches `name`,
+    /// ignoring case.
+    pub fn find_by_name(
+        &self,
+        name: &str,
+    ) -> impl Iterator<Item = &NeighborhoodNameVariant> {
+        let name = name.trim().to_lowercase();
+        self.iter().filter(move |variant| variant.name.to_lowercase() == name)
+    }
+}
+
+impl Default for NeighborhoodNameVariants {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collection for NeighborhoodNameVariants {
+    type Record = NeighborhoodNameVariant;
+    const ARCHIVE_ENTRY: &'static str = "LOG_VAR_BAI.TXT";
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity(capacity)
+    }
+}
+
+impl Extend<NeighborhoodNameVariant> for NeighborhoodNameVariants {
+    fn extend<I: IntoIterator<Item = NeighborhoodNameVariant>>(
+        &mut self,
+        iter: I,
+    ) {
+        for record in iter {
+            self.insert(record);
+        }
+    }
+}
+
+impl FromIterator<NeighborhoodNameVariant> for NeighborhoodNameVariants {
+    fn from_iter<I: IntoIterator<Item = NeighborhoodNameVariant>>(
+        iter: I,
+    ) -> Self {
+        let mut records = Self::new();
+        records.extend(iter);
+        records
+    }
+}
+
+impl Record for NeighborhoodNameVariant {
+    const FIELDS: &'static [&'static str] = &["BAI_NU", "VDB_NU", "VDB_TX"];
+
+    fn parse_line(
+        parser: &EdneParser,
+        line: &str,
+        line_number: usize,
+    ) -> Result<Self, ParseError> {
+        parse_neighborhood_variant_line(parser, line, line_number)
+    }
+}
+
+/// Parses a single LOG_VAR_BAI line into a `NeighborhoodNameVariant` struct.
+///
+/// # Field order (3 fields):
+/// 1. BAI_NU - Neighborhood ID
+/// 2. VDB_NU - Variant sequence number
+/// 3. VDB_TX - Variant name
+fn parse_neighborhood_variant_line(
+    parser: &EdneParser,
+    line: &str,
+    line_number: usize,
+) -> Result<NeighborhoodNameVariant, ParseError> {
+    let fields = parser.parse_line_checked(
+        line,
+        NEIGHBORHOOD_VARIANT_FIELD_COUNT,
+        line_number,
+    )?;
+
+    let id_str = EdneParser::required_field(fields[0], "BAI_NU", line_number)?;
+    let neighborhood_id = NeighborhoodId::from_str(&id_str).map_err(|e| {
+        ParseError::InvalidValue {
+            field_name: "BAI_NU",
+            value: id_str,
+            reason: e.to_string(),
+            source: Some(e.into()),
+            line_number,
+            span: None,
+        }
+    })?;
+
+    let sequence = EdneParser::parse_number(fields[1], "VDB_NU", line_number)?;
+    let name = EdneParser::required_field(fields[2], "VDB_TX", line_number)?;
+
+    Ok(NeighborhoodNameVariant { neighborhood_id, sequence, name })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_DATA: &str = "\
+17@1@Centro Histórico
+55400@1@Jaguar
+55400@2@Conjunto Jaguar";
+
+    #[test]
+    fn parse_sample_data() {
+        let variants =
+            NeighborhoodNameVariants::from_utf8(SAMPLE_DATA.to_string())
+                .unwrap();
+        assert_eq!(variants.len(), 3);
+    }
+
+    #[test]
+    fn parse_neighborhood_with_several_variants() {
+        let variants =
+            NeighborhoodNameVariants::from_utf8(SAMPLE_DATA.to_string())
+                .unwrap();
+        let jaguar = variants.get(&NeighborhoodId::new(55400)).unwrap();
+
+        assert_eq!(jaguar.len(), 2);
+        assert_eq!(jaguar[1].sequence, 2);
+        assert_eq!(jaguar[1].name, "Conjunto Jaguar");
+    }
+
+    #[test]
+    fn find_by_name_ignores_case() {
+        let variants =
+            NeighborhoodNameVariants::from_utf8(SAMPLE_DATA.to_string())
+                .unwrap();
+        let found: Vec<_> =
+            variants.find_by_name("CENTRO HISTÓRICO").collect();
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].neighborhood_id, NeighborhoodId::new(17));
+    }
+
+    #[test]
+    fn parse_invalid_field_count() {
+        let invalid = "55400@1@Jaguar@";
+        let result = NeighborhoodNameVariants::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::FieldCount { expected, got, .. } => {
+                assert_eq!(expected, 3);
+                assert_eq!(got, 4);
+            }
+            _ => panic!("Expected FieldCount error"),
+        }
+    }
+
+    #[test]
+    fn parse_invalid_neighborhood_id() {
+        let invalid = "abc@1@Jaguar";
+        let result = NeighborhoodNameVariants::from_utf8(invalid.to_string());
+        assert!(result.is_err());
+    }
+}
@@ -19,9 +19,13 @@ use std::{collections::HashMap, str::FromStr};
 use crate::{
     models::{
         LocalityId, Uf,
-        neighborhood::{Neighborhood, NeighborhoodId},
+        neighborhood::{Neighborhood, NeighborhoodId, NeighborhoodRef},
+    },
+    parser::{
+        base::{EdneParser, ParseError},
+        collection::{Collection, DeltaCollection},
+        record_stream::{Record, RecordRef},
     },
-    parser::base::{EdneParser, ParseError},
 };
 
 /// Expected number of fields in a neighborhood record.
@@ -65,51 +69,89 @@ impl Neighborhoods {
         self.0.insert(neighborhood.id, neighborhood)
     }
 
+    /// Removes a neighborhood by ID, returning it if it was present.
+    pub fn remove(&mut self, id: &NeighborhoodId) -> Option<Neighborhood> {
+        self.0.remove(id)
+    }
+
     /// Returns an iterator over all neighborhoods.
     pub fn iter(
         &self,
     ) -> impl Iterator<Item = (&NeighborhoodId, &Neighborhood)> {
         self.0.iter()
     }
+}
 
-    /// Parses neighborhoods from ISO-8859-1 encoded bytes.
-    ///
-    /// # Arguments
-    ///
-    /// * `bytes` - Raw file content in ISO-8859-1 encoding
-    ///
-    /// # Errors
-    ///
-    /// Returns `ParseError` if encoding fails or any line has invalid data.
-    pub fn from_iso8859_1(bytes: &[u8]) -> Result<Self, ParseError> {
-        let parser = EdneParser::from_iso8859_1(bytes)?;
-        Self::parse_with_parser(&parser)
+impl Default for Neighborhoods {
+    fn default() -> Self {
+        Self::new()
     }
+}
+
+impl Collection for Neighborhoods {
+    type Record = Neighborhood;
+    const ARCHIVE_ENTRY: &'static str = "LOG_BAIRRO.TXT";
 
-    /// Parses neighborhoods from UTF-8 string (for testing).
-    pub fn from_utf8(content: String) -> Result<Self, ParseError> {
-        let parser = EdneParser::from_utf8(content);
-        Self::parse_with_parser(&parser)
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity(capacity)
     }
+}
 
-    /// Internal method to parse neighborhoods using a configured parser.
-    fn parse_with_parser(parser: &EdneParser) -> Result<Self, ParseError> {
-        let lines: Vec<_> = parser.lines().collect();
-        let mut neighborhoods = Self::with_capacity(lines.len());
+impl DeltaCollection for Neighborhoods {
+    type Key = NeighborhoodId;
+    const OPERATION_FIELD: &'static str = "BAI_OPERACAO";
 
-        for (line_number, line) in lines {
-            let neighborhood =
-                parse_neighborhood_line(parser, line, line_number)?;
-            neighborhoods.insert(neighborhood);
+    fn key(record: &Neighborhood) -> NeighborhoodId {
+        record.id
+    }
+
+    fn insert(&mut self, record: Neighborhood) -> Option<Neighborhood> {
+        self.0.insert(record.id, record)
+    }
+
+    fn remove(&mut self, key: &NeighborhoodId) -> Option<Neighborhood> {
+        self.0.remove(key)
+    }
+}
+
+impl Extend<Neighborhood> for Neighborhoods {
+    fn extend<I: IntoIterator<Item = Neighborhood>>(&mut self, iter: I) {
+        for record in iter {
+            self.insert(record);
         }
+    }
+}
 
-        Ok(neighborhoods)
+impl FromIterator<Neighborhood> for Neighborhoods {
+    fn from_iter<I: IntoIterator<Item = Neighborhood>>(iter: I) -> Self {
+        let mut records = Self::new();
+        records.extend(iter);
+        records
     }
 }
 
-impl Default for Neighborhoods {
-    fn default() -> Self {
-        Self::new()
+impl Record for Neighborhood {
+    const FIELDS: &'static [&'static str] =
+        &["BAI_NU", "UFE_SG", "LOC_NU", "BAI_NO", "BAI_NO_ABREV"];
+
+    fn parse_line(
+        parser: &EdneParser,
+        line: &str,
+        line_number: usize,
+    ) -> Result<Self, ParseError> {
+        parse_neighborhood_line(parser, line, line_number)
+    }
+}
+
+impl<'a> RecordRef<'a> for NeighborhoodRef<'a> {
+    type Owned = Neighborhood;
+
+    fn parse_line(
+        parser: &EdneParser,
+        line: &'a str,
+        line_number: usize,
+    ) -> Result<Self, ParseError> {
+        parse_neighborhood_ref_line(parser, line, line_number)
     }
 }
 
@@ -126,6 +168,17 @@ fn parse_neighborhood_line(
     line: &str,
     line_number: usize,
 ) -> Result<Neighborhood, ParseError> {
+    parse_neighborhood_ref_line(parser, line, line_number)
+        .map(|r| r.to_owned())
+}
+
+/// Parses a single line into a borrowed `NeighborhoodRef`, without allocating its
+/// text fields. The field order is the same as [`parse_neighborhood_line`].
+fn parse_neighborhood_ref_line<'a>(
+    parser: &EdneParser,
+    line: &'a str,
+    line_number: usize,
+) -> Result<NeighborhoodRef<'a>, ParseError> {
     let fields = parser.parse_line_checked(
         line,
         NEIGHBORHOOD_FIELD_COUNT,
@@ -133,46 +186,53 @@ fn parse_neighborhood_line(
     )?;
 
     // Parse required fields
-    let id_str = EdneParser::required_field(fields[0], "BAI_NU", line_number)?;
-    let id = NeighborhoodId::from_str(&id_str).map_err(|e| {
+    let id_str = EdneParser::required_str(fields[0], "BAI_NU", line_number)?;
+    let id = NeighborhoodId::from_str(id_str).map_err(|e| {
         ParseError::InvalidValue {
             field_name: "BAI_NU",
-            value: id_str,
+            value: id_str.to_string(),
             reason: e.to_string(),
+            source: Some(e.into()),
             line_number,
+            span: None,
         }
     })?;
 
-    let uf_str = EdneParser::required_field(fields[1], "UFE_SG", line_number)?;
-    let uf = Uf::from_str(&uf_str).map_err(|e| ParseError::InvalidValue {
+    let uf_str = EdneParser::required_str(fields[1], "UFE_SG", line_number)?;
+    let uf = Uf::from_str(uf_str).map_err(|e| ParseError::InvalidValue {
         field_name: "UFE_SG",
-        value: uf_str,
+        value: uf_str.to_string(),
         reason: e.to_string(),
+        source: Some(e.into()),
         line_number,
+        span: None,
     })?;
 
     let loc_id_str =
-        EdneParser::required_field(fields[2], "LOC_NU", line_number)?;
-    let locality_id = LocalityId::from_str(&loc_id_str).map_err(|e| {
+        EdneParser::required_str(fields[2], "LOC_NU", line_number)?;
+    let locality_id = LocalityId::from_str(loc_id_str).map_err(|e| {
         ParseError::InvalidValue {
             field_name: "LOC_NU",
-            value: loc_id_str,
+            value: loc_id_str.to_string(),
             reason: e.to_string(),
+            source: Some(e.into()),
             line_number,
+            span: None,
         }
     })?;
 
-    let name = EdneParser::required_field(fields[3], "BAI_NO", line_number)?;
+    let name = EdneParser::required_str(fields[3], "BAI_NO", line_number)?;
 
     // Parse optional field
-    let abbreviated_name = EdneParser::optional_field(fields[4]);
+    let abbreviated_name = EdneParser::optional_str(fields[4]);
 
-    Ok(Neighborhood { id, uf, locality_id, name, abbreviated_name })
+    Ok(NeighborhoodRef { id, uf, locality_id, name, abbreviated_name })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parser::base::Layout;
 
     const SAMPLE_DATA: &str = "\
 55400@AC@16@Loteamento Jaguar@Lot Jaguar
@@ -322,4 +382,61 @@ mod tests {
         let result = neighborhoods.get(&NeighborhoodId::new(99999));
         assert!(result.is_none());
     }
+
+    const DELTA_DATA: &str = "\
+55400@AC@16@Nome Novo@Lot Jaguar@UPD
+55402@AC@16@Loteamento Santa Luzia@Lot Sta Luzia@DEL
+999999@AC@16@Loteamento Jaguar@Lot Jaguar@INS";
+
+    #[test]
+    fn apply_delta_to_base() {
+        let mut neighborhoods =
+            Neighborhoods::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        let before = neighborhoods.len();
+        let deltas =
+            Neighborhoods::deltas_from_utf8(DELTA_DATA.to_string()).unwrap();
+        assert_eq!(deltas.len(), 3);
+
+        for delta in deltas {
+            neighborhoods.apply_delta(delta);
+        }
+
+        assert_eq!(neighborhoods.len(), before);
+        assert_eq!(
+            neighborhoods.get(&NeighborhoodId::new(55400)).unwrap().name,
+            "Nome Novo"
+        );
+        assert!(neighborhoods.get(&NeighborhoodId::new(55402)).is_none());
+        assert!(neighborhoods.get(&NeighborhoodId::new(999999)).is_some());
+    }
+
+    #[test]
+    fn parse_delta_invalid_operation() {
+        let invalid = "55402@AC@16@Loteamento Santa Luzia@Lot Sta Luzia@XXX";
+        let result = Neighborhoods::deltas_from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::InvalidValue { field_name, .. } => {
+                assert_eq!(field_name, "BAI_OPERACAO");
+            }
+            _ => panic!("Expected InvalidValue error"),
+        }
+    }
+
+    #[test]
+    fn parse_fixed_width_layout() {
+        let content = "\
+00055400AC00000016Loteamento Jaguar        Lot Jaguar
+00055403AC00000016Habitasa                 Habitasa"
+            .to_string();
+        let parser = EdneParser::from_utf8(content)
+            .with_layout(Layout::fixed_width([0, 8, 10, 18, 43]));
+        let neighborhoods = Neighborhoods::from_parser(&parser).unwrap();
+
+        assert_eq!(neighborhoods.len(), 2);
+        let jaguar = neighborhoods.get(&NeighborhoodId::new(55400)).unwrap();
+        assert_eq!(jaguar.uf, Uf::AC);
+        assert_eq!(jaguar.locality_id, LocalityId::new(16));
+        assert_eq!(jaguar.name, "Loteamento Jaguar");
+        assert_eq!(jaguar.abbreviated_name.as_deref(), Some("Lot Jaguar"));
+    }
 }
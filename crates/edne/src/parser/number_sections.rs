@@ -0,0 +1,284 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+use std::{collections::HashMap, str::FromStr};
+
+use crate::{
+    models::{
+        AddressId,
+        number_section::{NumberSection, SectionSide},
+    },
+    parser::{
+        base::{EdneParser, ParseError},
+        collection::Collection,
+        record_stream::Record,
+    },
+};
+
+/// Expected number of fields in a numbering section record.
+const NUMBER_SECTION_FIELD_COUNT: usize = 4;
+
+/// Collection of street numbering sections indexed by their address ID.
+#[derive(Debug, Clone)]
+pub struct NumberSections(HashMap<AddressId, NumberSection>);
+
+impl NumberSections {
+    /// Creates a new empty collection.
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Creates a collection with pre-allocated capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(HashMap::with_capacity(capacity))
+    }
+
+    /// Returns the number of sections.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the collection is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Gets the section of a street segment by address ID.
+    pub fn get(&self, id: &AddressId) -> Option<&NumberSection> {
+        self.0.get(id)
+    }
+
+    /// Inserts a section into the collection.
+    pub fn insert(&mut self, section: NumberSection) -> Option<NumberSection> {
+        self.0.insert(section.address_id, section)
+    }
+
+    /// Returns an iterator over all sections.
+    pub fn iter(&self) -> impl Iterator<Item = (&AddressId, &NumberSection)> {
+        self.0.iter()
+    }
+
+    /// Returns the section of `address_id` if it covers the house `number`.
+    ///
+    /// Use it to pick, among the segments of a long street, the one (and so
+    /// the CEP) that applies to a given number.
+    pub fn section_for(
+        &self,
+        address_id: &AddressId,
+        number: u32,
+    ) -> Option<&NumberSection> {
+        self.get(address_id).filter(|section| section.contains(number))
+    }
+}
+
+impl Default for NumberSections {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collection for NumberSections {
+    type Record = NumberSection;
+    const ARCHIVE_ENTRY: &'static str = "LOG_NUM_SEC.TXT";
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity(capacity)
+    }
+}
+
+impl Extend<NumberSection> for NumberSections {
+    fn extend<I: IntoIterator<Item = NumberSection>>(&mut self, iter: I) {
+        for record in iter {
+            self.insert(record);
+        }
+    }
+}
+
+impl FromIterator<NumberSection> for NumberSections {
+    fn from_iter<I: IntoIterator<Item = NumberSection>>(iter: I) -> Self {
+        let mut records = Self::new();
+        records.extend(iter);
+        records
+    }
+}
+
+impl Record for NumberSection {
+    const FIELDS: &'static [&'static str] =
+        &["LOG_NU", "SEC_NU_INI", "SEC_NU_FIM", "SEC_IN_LADO"];
+
+    fn parse_line(
+        parser: &EdneParser,
+        line: &str,
+        line_number: usize,
+    ) -> Result<Self, ParseError> {
+        parse_number_section_line(parser, line, line_number)
+    }
+}
+
+/// Parses a single LOG_NUM_SEC line into a `NumberSection` struct.
+///
+/// # Field order (4 fields):
+/// 1. LOG_NU - Address ID
+/// 2. SEC_NU_INI - First house number
+/// 3. SEC_NU_FIM - Last house number
+/// 4. SEC_IN_LADO - Side of the street
+fn parse_number_section_line(
+    parser: &EdneParser,
+    line: &str,
+    line_number: usize,
+) -> Result<NumberSection, ParseError> {
+    let fields = parser.parse_line_checked(
+        line,
+        NUMBER_SECTION_FIELD_COUNT,
+        line_number,
+    )?;
+
+    let id_str = EdneParser::required_field(fields[0], "LOG_NU", line_number)?;
+    let address_id = AddressId::from_str(&id_str).map_err(|e| {
+        ParseError::InvalidValue {
+            field_name: "LOG_NU",
+            value: id_str,
+            reason: e.to_string(),
+            source: Some(e.into()),
+            line_number,
+            span: None,
+        }
+    })?;
+
+    let number_start =
+        EdneParser::parse_number(fields[1], "SEC_NU_INI", line_number)?;
+    let number_end: u32 =
+        EdneParser::parse_number(fields[2], "SEC_NU_FIM", line_number)?;
+    if number_start > number_end {
+        return Err(ParseError::InvalidValue {
+            field_name: "SEC_NU_FIM",
+            value: fields[2].to_string(),
+            reason: format!(
+                "section end is lower than start '{}'",
+                number_start
+            ),
+            line_number,
+            source: None,
+            span: None,
+        });
+    }
+
+    let side_str =
+        EdneParser::required_field(fields[3], "SEC_IN_LADO", line_number)?;
+    let side = SectionSide::from_str(&side_str).map_err(|e| {
+        ParseError::InvalidValue {
+            field_name: "SEC_IN_LADO",
+            value: side_str,
+            reason: e.to_string(),
+            source: Some(e.into()),
+            line_number,
+            span: None,
+        }
+    })?;
+
+    Ok(NumberSection { address_id, number_start, number_end, side })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_DATA: &str = "\
+1@1@999@A
+1004886@1@1099@I
+1004887@2@1100@P
+1004888@1@500@D";
+
+    #[test]
+    fn parse_sample_data() {
+        let sections =
+            NumberSections::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        assert_eq!(sections.len(), 4);
+    }
+
+    #[test]
+    fn parse_section_with_all_fields() {
+        let sections =
+            NumberSections::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        let id = AddressId::new(1004886);
+        let section = sections.get(&id).unwrap();
+
+        assert_eq!(section.address_id, id);
+        assert_eq!(section.number_start, 1);
+        assert_eq!(section.number_end, 1099);
+        assert_eq!(section.side, SectionSide::Odd);
+    }
+
+    #[test]
+    fn section_for_number_in_range() {
+        let sections =
+            NumberSections::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        let even = AddressId::new(1004887);
+
+        assert!(sections.section_for(&even, 578).is_some());
+        assert!(sections.section_for(&even, 579).is_none());
+        assert!(sections.section_for(&even, 1578).is_none());
+        assert!(sections.section_for(&AddressId::new(99999), 1).is_none());
+    }
+
+    #[test]
+    fn parse_invalid_field_count() {
+        let invalid = "1@1@999";
+        let result = NumberSections::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::FieldCount { expected, got, .. } => {
+                assert_eq!(expected, 4);
+                assert_eq!(got, 3);
+            }
+            _ => panic!("Expected FieldCount error"),
+        }
+    }
+
+    #[test]
+    fn parse_invalid_number() {
+        let invalid = "1@1@abc@A";
+        let result = NumberSections::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::InvalidNumber { field_name, .. } => {
+                assert_eq!(field_name, "SEC_NU_FIM");
+            }
+            _ => panic!("Expected InvalidNumber error"),
+        }
+    }
+
+    #[test]
+    fn parse_inverted_section() {
+        let invalid = "1@999@0001@A";
+        let result = NumberSections::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::InvalidValue { field_name, value, span, .. } => {
+                assert_eq!(field_name, "SEC_NU_FIM");
+                // The value is the field as written, matching the span.
+                assert_eq!(value, "0001");
+                let span = span.unwrap();
+                assert_eq!(&invalid[span.byte_offset..][..span.width], value);
+            }
+            _ => panic!("Expected InvalidValue error"),
+        }
+    }
+
+    #[test]
+    fn parse_invalid_side() {
+        let invalid = "1@1@999@X";
+        let result = NumberSections::from_utf8(invalid.to_string());
+        assert!(result.is_err());
+    }
+}
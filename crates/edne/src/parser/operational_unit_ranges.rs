@@ -0,0 +1,267 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+use std::{collections::HashMap, str::FromStr};
+
+use crate::{
+    models::{OperationalUnitId, post_box_range::OperationalUnitPostBoxRange},
+    parser::{
+        base::{EdneParser, ParseError},
+        collection::Collection,
+        record_stream::Record,
+    },
+};
+
+/// Expected number of fields in an operational unit post box range record.
+const OPERATIONAL_UNIT_RANGE_FIELD_COUNT: usize = 3;
+
+/// Collection of operational unit post box ranges indexed by their operational unit ID.
+#[derive(Debug, Clone)]
+pub struct OperationalUnitPostBoxRanges(
+    HashMap<OperationalUnitId, Vec<OperationalUnitPostBoxRange>>,
+);
+
+impl OperationalUnitPostBoxRanges {
+    /// Creates a new empty collection.
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Creates a collection with pre-allocated capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(HashMap::with_capacity(capacity))
+    }
+
+    /// Returns the number of ranges.
+    pub fn len(&self) -> usize {
+        self.0.values().map(Vec::len).sum()
+    }
+
+    /// Returns `true` if the collection is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Gets all ranges of an operational unit.
+    pub fn get(
+        &self,
+        id: &OperationalUnitId,
+    ) -> Option<&[OperationalUnitPostBoxRange]> {
+        self.0.get(id).map(Vec::as_slice)
+    }
+
+    /// Inserts a range into the collection.
+    pub fn insert(&mut self, range: OperationalUnitPostBoxRange) {
+        self.0.entry(range.operational_unit_id).or_default().push(range);
+    }
+
+    /// Returns an iterator over all ranges.
+    pub fn iter(&self) -> impl Iterator<Item = &OperationalUnitPostBoxRange> {
+        self.0.values().flatten()
+    }
+
+    /// Returns `true` if post box `number` belongs to an operational unit `id`.
+    pub fn has_post_box(&self, id: &OperationalUnitId, number: u32) -> bool {
+        self.get(id)
+            .is_some_and(|ranges| ranges.iter().any(|r| r.contains(number)))
+    }
+}
+
+impl Default for OperationalUnitPostBoxRanges {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collection for OperationalUnitPostBoxRanges {
+    type Record = OperationalUnitPostBoxRange;
+    const ARCHIVE_ENTRY: &'static str = "LOG_FAIXA_UOP.TXT";
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity(capacity)
+    }
+}
+
+impl Extend<OperationalUnitPostBoxRange> for OperationalUnitPostBoxRanges {
+    fn extend<I: IntoIterator<Item = OperationalUnitPostBoxRange>>(
+        &mut self,
+        iter: I,
+    ) {
+        for record in iter {
+            self.insert(record);
+        }
+    }
+}
+
+impl FromIterator<OperationalUnitPostBoxRange>
+    for OperationalUnitPostBoxRanges
+{
+    fn from_iter<I: IntoIterator<Item = OperationalUnitPostBoxRange>>(
+        iter: I,
+    ) -> Self {
+        let mut records = Self::new();
+        records.extend(iter);
+        records
+    }
+}
+
+impl Record for OperationalUnitPostBoxRange {
+    const FIELDS: &'static [&'static str] =
+        &["UOP_NU", "FNC_INICIAL", "FNC_FINAL"];
+
+    fn parse_line(
+        parser: &EdneParser,
+        line: &str,
+        line_number: usize,
+    ) -> Result<Self, ParseError> {
+        parse_operational_unit_range_line(parser, line, line_number)
+    }
+}
+
+/// Parses a single LOG_FAIXA_UOP line into a `OperationalUnitPostBoxRange` struct.
+///
+/// # Field order (3 fields):
+/// 1. UOP_NU - Operational unit ID
+/// 2. FNC_INICIAL - First post box number
+/// 3. FNC_FINAL - Last post box number
+fn parse_operational_unit_range_line(
+    parser: &EdneParser,
+    line: &str,
+    line_number: usize,
+) -> Result<OperationalUnitPostBoxRange, ParseError> {
+    let fields = parser.parse_line_checked(
+        line,
+        OPERATIONAL_UNIT_RANGE_FIELD_COUNT,
+        line_number,
+    )?;
+
+    let id_str = EdneParser::required_field(fields[0], "UOP_NU", line_number)?;
+    let operational_unit_id =
+        OperationalUnitId::from_str(&id_str).map_err(|e| {
+            ParseError::InvalidValue {
+                field_name: "UOP_NU",
+                value: id_str,
+                reason: e.to_string(),
+                source: Some(e.into()),
+                line_number,
+                span: None,
+            }
+        })?;
+
+    let box_start =
+        EdneParser::parse_number(fields[1], "FNC_INICIAL", line_number)?;
+    let box_end: u32 =
+        EdneParser::parse_number(fields[2], "FNC_FINAL", line_number)?;
+    if box_start > box_end {
+        return Err(ParseError::InvalidValue {
+            field_name: "FNC_FINAL",
+            value: fields[2].to_string(),
+            reason: format!("range end is lower than start '{}'", box_start),
+            line_number,
+            source: None,
+            span: None,
+        });
+    }
+
+    Ok(OperationalUnitPostBoxRange { operational_unit_id, box_start, box_end })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_DATA: &str = "\
+1@1@500
+1@1001@1500
+5@1@999
+24821@1@300";
+
+    #[test]
+    fn parse_sample_data() {
+        let ranges =
+            OperationalUnitPostBoxRanges::from_utf8(SAMPLE_DATA.to_string())
+                .unwrap();
+        assert_eq!(ranges.len(), 4);
+    }
+
+    #[test]
+    fn parse_unit_with_several_ranges() {
+        let ranges =
+            OperationalUnitPostBoxRanges::from_utf8(SAMPLE_DATA.to_string())
+                .unwrap();
+        let found = ranges.get(&OperationalUnitId::new(1)).unwrap();
+
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].box_start, 1);
+        assert_eq!(found[0].box_end, 500);
+        assert_eq!(found[1].box_start, 1001);
+        assert_eq!(found[1].box_end, 1500);
+    }
+
+    #[test]
+    fn has_post_box_in_range() {
+        let ranges =
+            OperationalUnitPostBoxRanges::from_utf8(SAMPLE_DATA.to_string())
+                .unwrap();
+        let id = OperationalUnitId::new(1);
+
+        assert!(ranges.has_post_box(&id, 250));
+        assert!(ranges.has_post_box(&id, 1234));
+        assert!(!ranges.has_post_box(&id, 750));
+        assert!(!ranges.has_post_box(&OperationalUnitId::new(99999), 250));
+    }
+
+    #[test]
+    fn parse_invalid_field_count() {
+        let invalid = "1@1";
+        let result =
+            OperationalUnitPostBoxRanges::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::FieldCount { expected, got, .. } => {
+                assert_eq!(expected, 3);
+                assert_eq!(got, 2);
+            }
+            _ => panic!("Expected FieldCount error"),
+        }
+    }
+
+    #[test]
+    fn parse_invalid_number() {
+        let invalid = "1@abc@500";
+        let result =
+            OperationalUnitPostBoxRanges::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::InvalidNumber { field_name, .. } => {
+                assert_eq!(field_name, "FNC_INICIAL");
+            }
+            _ => panic!("Expected InvalidNumber error"),
+        }
+    }
+
+    #[test]
+    fn parse_inverted_range() {
+        let invalid = "1@500@0001";
+        let result =
+            OperationalUnitPostBoxRanges::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::InvalidValue { field_name, value, .. } => {
+                assert_eq!(field_name, "FNC_FINAL");
+                assert_eq!(value, "0001");
+            }
+            _ => panic!("Expected InvalidValue error"),
+        }
+    }
+}
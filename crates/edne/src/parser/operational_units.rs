@@ -20,10 +20,16 @@ use crate::{
     models::{
         LocalityId, NeighborhoodId, StreetId, Uf,
         operational_unit::{
-            OperationalUnit, OperationalUnitId, PostBoxIndicator,
+            OperationalUnit, OperationalUnitId, OperationalUnitRef,
+            PostBoxIndicator,
         },
     },
-    parser::base::{EdneParser, ParseError},
+    parser::{
+        base::{EdneParser, ParseError},
+        collection::{Collection, DeltaCollection},
+        helpers,
+        record_stream::{Record, RecordRef},
+    },
 };
 
 const OPERATIONAL_UNIT_FIELD_COUNT: usize = 10;
@@ -59,38 +65,101 @@ impl OperationalUnits {
         self.0.insert(unit.id, unit)
     }
 
+    /// Removes an operational unit by ID, returning it if it was present.
+    pub fn remove(
+        &mut self,
+        id: &OperationalUnitId,
+    ) -> Option<OperationalUnit> {
+        self.0.remove(id)
+    }
+
     pub fn iter(
         &self,
     ) -> impl Iterator<Item = (&OperationalUnitId, &OperationalUnit)> {
         self.0.iter()
     }
+}
+
+impl Default for OperationalUnits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collection for OperationalUnits {
+    type Record = OperationalUnit;
+    const ARCHIVE_ENTRY: &'static str = "LOG_UNID_OPER.TXT";
 
-    pub fn from_iso8859_1(bytes: &[u8]) -> Result<Self, ParseError> {
-        let parser = EdneParser::from_iso8859_1(bytes)?;
-        Self::parse_with_parser(&parser)
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity(capacity)
     }
+}
 
-    pub fn from_utf8(content: String) -> Result<Self, ParseError> {
-        let parser = EdneParser::from_utf8(content);
-        Self::parse_with_parser(&parser)
+impl DeltaCollection for OperationalUnits {
+    type Key = OperationalUnitId;
+    const OPERATION_FIELD: &'static str = "UOP_OPERACAO";
+
+    fn key(record: &OperationalUnit) -> OperationalUnitId {
+        record.id
     }
 
-    fn parse_with_parser(parser: &EdneParser) -> Result<Self, ParseError> {
-        let lines: Vec<_> = parser.lines().collect();
-        let mut units = Self::with_capacity(lines.len());
+    fn insert(&mut self, record: OperationalUnit) -> Option<OperationalUnit> {
+        self.0.insert(record.id, record)
+    }
 
-        for (line_number, line) in lines {
-            let unit = parse_operational_unit_line(parser, line, line_number)?;
-            units.insert(unit);
+    fn remove(&mut self, key: &OperationalUnitId) -> Option<OperationalUnit> {
+        self.0.remove(key)
+    }
+}
+
+impl Extend<OperationalUnit> for OperationalUnits {
+    fn extend<I: IntoIterator<Item = OperationalUnit>>(&mut self, iter: I) {
+        for record in iter {
+            self.insert(record);
         }
+    }
+}
 
-        Ok(units)
+impl FromIterator<OperationalUnit> for OperationalUnits {
+    fn from_iter<I: IntoIterator<Item = OperationalUnit>>(iter: I) -> Self {
+        let mut records = Self::new();
+        records.extend(iter);
+        records
     }
 }
 
-impl Default for OperationalUnits {
-    fn default() -> Self {
-        Self::new()
+impl Record for OperationalUnit {
+    const FIELDS: &'static [&'static str] = &[
+        "UOP_NU",
+        "UFE_SG",
+        "LOC_NU",
+        "BAI_NU",
+        "LOG_NU",
+        "UOP_NO",
+        "UOP_ENDERECO",
+        "CEP",
+        "UOP_IN_CP",
+        "UOP_NO_ABREV",
+    ];
+
+    fn parse_line(
+        parser: &EdneParser,
+        line: &str,
+        line_number: usize,
+    ) -> Result<Self, ParseError> {
+        parse_operational_unit_line(parser, line, line_number)
+    }
+}
+
+impl<'a> RecordRef<'a> for OperationalUnitRef<'a> {
+    type Owned = OperationalUnit;
+
+    fn parse_line(
+        parser: &EdneParser,
+        line: &'a str,
+        line_number: usize,
+    ) -> Result<Self, ParseError> {
+        parse_operational_unit_ref_line(parser, line, line_number)
     }
 }
 
@@ -99,85 +168,108 @@ fn parse_operational_unit_line(
     line: &str,
     line_number: usize,
 ) -> Result<OperationalUnit, ParseError> {
+    parse_operational_unit_ref_line(parser, line, line_number)
+        .map(|r| r.to_owned())
+}
+
+/// Parses a single line into a borrowed `OperationalUnitRef`, without allocating its
+/// text fields. The field order is the same as [`parse_operational_unit_line`].
+fn parse_operational_unit_ref_line<'a>(
+    parser: &EdneParser,
+    line: &'a str,
+    line_number: usize,
+) -> Result<OperationalUnitRef<'a>, ParseError> {
     let fields = parser.parse_line_checked(
         line,
         OPERATIONAL_UNIT_FIELD_COUNT,
         line_number,
     )?;
 
-    let id_str = EdneParser::required_field(fields[0], "UOP_NU", line_number)?;
-    let id = OperationalUnitId::from_str(&id_str).map_err(|e| {
+    let id_str = EdneParser::required_str(fields[0], "UOP_NU", line_number)?;
+    let id = OperationalUnitId::from_str(id_str).map_err(|e| {
         ParseError::InvalidValue {
             field_name: "UOP_NU",
-            value: id_str,
+            value: id_str.to_string(),
             reason: e.to_string(),
+            source: Some(e.into()),
             line_number,
+            span: None,
         }
     })?;
 
-    let uf_str = EdneParser::required_field(fields[1], "UFE_SG", line_number)?;
-    let uf = Uf::from_str(&uf_str).map_err(|e| ParseError::InvalidValue {
+    let uf_str = EdneParser::required_str(fields[1], "UFE_SG", line_number)?;
+    let uf = Uf::from_str(uf_str).map_err(|e| ParseError::InvalidValue {
         field_name: "UFE_SG",
-        value: uf_str,
+        value: uf_str.to_string(),
         reason: e.to_string(),
+        source: Some(e.into()),
         line_number,
+        span: None,
     })?;
 
     let loc_id_str =
-        EdneParser::required_field(fields[2], "LOC_NU", line_number)?;
-    let locality_id = LocalityId::from_str(&loc_id_str).map_err(|e| {
+        EdneParser::required_str(fields[2], "LOC_NU", line_number)?;
+    let locality_id = LocalityId::from_str(loc_id_str).map_err(|e| {
         ParseError::InvalidValue {
             field_name: "LOC_NU",
-            value: loc_id_str,
+            value: loc_id_str.to_string(),
             reason: e.to_string(),
+            source: Some(e.into()),
             line_number,
+            span: None,
         }
     })?;
 
     let bai_id_str =
-        EdneParser::required_field(fields[3], "BAI_NU", line_number)?;
+        EdneParser::required_str(fields[3], "BAI_NU", line_number)?;
     let neighborhood_id =
-        NeighborhoodId::from_str(&bai_id_str).map_err(|e| {
+        NeighborhoodId::from_str(bai_id_str).map_err(|e| {
             ParseError::InvalidValue {
                 field_name: "BAI_NU",
-                value: bai_id_str,
+                value: bai_id_str.to_string(),
                 reason: e.to_string(),
+                source: Some(e.into()),
                 line_number,
+                span: None,
             }
         })?;
 
     let street_id =
-        if let Some(log_id_str) = EdneParser::optional_field(fields[4]) {
-            Some(StreetId::from_str(&log_id_str).map_err(|e| {
+        if let Some(log_id_str) = EdneParser::optional_str(fields[4]) {
+            Some(StreetId::from_str(log_id_str).map_err(|e| {
                 ParseError::InvalidValue {
                     field_name: "LOG_NU",
-                    value: log_id_str,
+                    value: log_id_str.to_string(),
                     reason: e.to_string(),
+                    source: Some(e.into()),
                     line_number,
+                    span: None,
                 }
             })?)
         } else {
             None
         };
 
-    let name = EdneParser::required_field(fields[5], "UOP_NO", line_number)?;
+    let name = EdneParser::required_str(fields[5], "UOP_NO", line_number)?;
     let address =
-        EdneParser::required_field(fields[6], "UOP_ENDERECO", line_number)?;
-    let cep = EdneParser::required_field(fields[7], "CEP", line_number)?;
+        EdneParser::required_str(fields[6], "UOP_ENDERECO", line_number)?;
+    let cep = helpers::required_cep(fields[7], "CEP", line_number)?;
 
     let indicator_str =
-        EdneParser::required_field(fields[8], "UOP_IN_CP", line_number)?;
-    let post_box_indicator = PostBoxIndicator::from_str(&indicator_str)
+        EdneParser::required_str(fields[8], "UOP_IN_CP", line_number)?;
+    let post_box_indicator = PostBoxIndicator::from_str(indicator_str)
         .map_err(|e| ParseError::InvalidValue {
             field_name: "UOP_IN_CP",
-            value: indicator_str,
+            value: indicator_str.to_string(),
             reason: e.to_string(),
+            source: Some(e.into()),
             line_number,
+            span: None,
         })?;
 
-    let abbreviated_name = EdneParser::optional_field(fields[9]);
+    let abbreviated_name = EdneParser::optional_str(fields[9]);
 
-    Ok(OperationalUnit {
+    Ok(OperationalUnitRef {
         id,
         uf,
         locality_id,
@@ -248,4 +340,44 @@ mod tests {
         let result = OperationalUnits::from_utf8(invalid.to_string());
         assert!(result.is_err());
     }
+
+    const DELTA_DATA: &str = "\
+48437@AC@11059@51784@@Nome Novo@Rua Kaxinawás, s/n@69929970@N@AGC Campinas@UPD
+11986@AC@5@39323@@AC Capixaba@Avenida Governador Edmundo Pinto, 711@69931970@N@AC Capixaba@DEL
+999999@AC@11059@51784@@AGC Campinas@Rua Kaxinawás, s/n@69929970@N@AGC Campinas@INS";
+
+    #[test]
+    fn apply_delta_to_base() {
+        let mut units =
+            OperationalUnits::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        let before = units.len();
+        let deltas =
+            OperationalUnits::deltas_from_utf8(DELTA_DATA.to_string())
+                .unwrap();
+        assert_eq!(deltas.len(), 3);
+
+        for delta in deltas {
+            units.apply_delta(delta);
+        }
+
+        assert_eq!(units.len(), before);
+        assert_eq!(
+            units.get(&OperationalUnitId::new(48437)).unwrap().name,
+            "Nome Novo"
+        );
+        assert!(units.get(&OperationalUnitId::new(11986)).is_none());
+        assert!(units.get(&OperationalUnitId::new(999999)).is_some());
+    }
+
+    #[test]
+    fn parse_delta_invalid_operation() {
+        let invalid = "11986@AC@5@39323@@AC Capixaba@Avenida Governador Edmundo Pinto, 711@69931970@N@AC Capixaba@XXX";
+        let result = OperationalUnits::deltas_from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::InvalidValue { field_name, .. } => {
+                assert_eq!(field_name, "UOP_OPERACAO");
+            }
+            _ => panic!("Expected InvalidValue error"),
+        }
+    }
 }
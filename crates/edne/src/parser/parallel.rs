@@ -0,0 +1,163 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+//! Parallel parsing of large files (`parallel` feature).
+//!
+//! The decoded content is split into line-aligned chunks, one per available
+//! thread. Each chunk knows the number of its first line, so errors carry
+//! the same line numbers as a sequential parse.
+
+use std::thread;
+
+use crate::parser::{
+    base::{EdneParser, ParseError, split_lines},
+    record_stream::Record,
+};
+
+/// Parses every line of `parser` on multiple threads.
+///
+/// Returns the records of each chunk, in file order.
+///
+/// # Errors
+///
+/// Returns the `ParseError` of the first invalid line in the file.
+pub(crate) fn parse_parallel<T>(
+    parser: &EdneParser,
+) -> Result<Vec<Vec<T>>, ParseError>
+where
+    T: Record + Send,
+{
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    let chunks = split_chunks(parser.content(), threads);
+    let encoding = parser.encoding();
+
+    thread::scope(|scope| {
+        let mut offset = 0;
+        let handles: Vec<_> = chunks
+            .into_iter()
+            .map(|(first_line, chunk)| {
+                let first_offset = offset;
+                offset += encoding.encoded_len(chunk);
+                scope.spawn(move || {
+                    parse_chunk(parser, chunk, first_line, first_offset)
+                })
+            })
+            .collect();
+
+        // Joining in order returns the error of the earliest chunk first.
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("parser thread panicked"))
+            .collect()
+    })
+}
+
+/// Parses the lines of a chunk whose first line is `first_line`, starting
+/// at byte `first_offset`.
+fn parse_chunk<T: Record>(
+    parser: &EdneParser,
+    chunk: &str,
+    first_line: usize,
+    first_offset: usize,
+) -> Result<Vec<T>, ParseError> {
+    split_lines(chunk, first_line, first_offset, parser.encoding())
+        .map(|line| parser.parse_located::<T>(&line))
+        .collect()
+}
+
+/// Splits `content` into at most `count` chunks ending on line boundaries,
+/// each paired with the number of its first line.
+fn split_chunks(content: &str, count: usize) -> Vec<(usize, &str)> {
+    let target = content.len().div_ceil(count.max(1)).max(1);
+    let mut chunks = Vec::with_capacity(count);
+    let mut rest = content;
+    let mut first_line = 1;
+
+    while !rest.is_empty() {
+        let mut at = target.min(rest.len());
+        while !rest.is_char_boundary(at) {
+            at += 1;
+        }
+        let end = rest[at..].find('\n').map_or(rest.len(), |pos| at + pos + 1);
+        let (chunk, tail) = rest.split_at(end);
+        chunks.push((first_line, chunk));
+        first_line += chunk.matches('\n').count();
+        rest = tail;
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Neighborhood;
+
+    #[test]
+    fn split_chunks_on_line_boundaries() {
+        let content = "a@1\nb@2\nc@3\nd@4\ne@5";
+        let chunks = split_chunks(content, 2);
+
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks[0].1.ends_with('\n'));
+        assert_eq!(chunks[1].0, 1 + chunks[0].1.matches('\n').count());
+        let joined: String = chunks.iter().map(|(_, c)| *c).collect();
+        assert_eq!(joined, content);
+    }
+
+    #[test]
+    fn split_chunks_empty_content() {
+        assert!(split_chunks("", 4).is_empty());
+    }
+
+    #[test]
+    fn parse_parallel_keeps_line_numbers() {
+        let mut content = String::new();
+        for id in 1..=1000 {
+            content.push_str(&format!("{}@AC@16@Bairro {}@\n", id, id));
+        }
+        content.push_str("\nabc@AC@16@Bairro@\n");
+        let parser = EdneParser::from_utf8(content);
+
+        let err = parse_parallel::<Neighborhood>(&parser).unwrap_err();
+        let sequential = parser
+            .located_lines()
+            .find_map(|line| parser.parse_located::<Neighborhood>(&line).err())
+            .unwrap();
+        assert_eq!(err.span(), sequential.span());
+        match err {
+            ParseError::InvalidValue { field_name, line_number, .. } => {
+                assert_eq!(field_name, "BAI_NU");
+                assert_eq!(line_number, 1002);
+            }
+            _ => panic!("Expected InvalidValue error"),
+        }
+    }
+
+    #[test]
+    fn parse_parallel_returns_all_records() {
+        let mut content = String::new();
+        for id in 1..=1000 {
+            content.push_str(&format!("{}@AC@16@Bairro {}@\n", id, id));
+        }
+        let parser = EdneParser::from_utf8(content);
+        let chunks = parse_parallel::<Neighborhood>(&parser).unwrap();
+
+        let records: Vec<_> = chunks.into_iter().flatten().collect();
+        assert_eq!(records.len(), 1000);
+        assert_eq!(records[999].name, "Bairro 1000");
+    }
+}
@@ -0,0 +1,431 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+//! Streaming parsing of eDNE files.
+//!
+//! The collections decode a whole file and index it in a `HashMap`. For
+//! large files that are only read once (e.g. LOG_LOGRADOURO_SP piped into a
+//! database), [`stream`] yields records one at a time from any `BufRead`,
+//! keeping only the current line in memory.
+
+use std::{
+    io::{BufRead, BufReader, Read},
+    marker::PhantomData,
+};
+
+use crate::parser::{
+    base::{EdneParser, Layout, ParseError},
+    encoding::Encoding,
+    span::SourceSpan,
+};
+
+/// A record type that can be parsed from a single eDNE line.
+pub trait Record: Sized {
+    /// Names of the columns of a line, in file order.
+    ///
+    /// Used to point error spans at the failing field.
+    const FIELDS: &'static [&'static str];
+
+    /// Parses a single line into a record.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if the line has invalid data.
+    fn parse_line(
+        parser: &EdneParser,
+        line: &str,
+        line_number: usize,
+    ) -> Result<Self, ParseError>;
+}
+
+/// A borrowed record type whose text fields are slices of a decoded line.
+pub trait RecordRef<'a>: Sized {
+    /// Owned record type produced by `to_owned()`.
+    type Owned: Record;
+
+    /// Parses a single line into a borrowed record.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ParseError` if the line has invalid data.
+    fn parse_line(
+        parser: &EdneParser,
+        line: &'a str,
+        line_number: usize,
+    ) -> Result<Self, ParseError>;
+}
+
+/// Line-by-line decoding state shared by the sync and async streams.
+pub(crate) struct LineState {
+    parser: EdneParser,
+    encoding: Encoding,
+    file_name: Option<String>,
+    pub(crate) buffer: Vec<u8>,
+    line_number: usize,
+    offset: usize,
+    line_offset: usize,
+}
+
+impl LineState {
+    pub(crate) fn new(layout: Layout) -> Self {
+        Self {
+            parser: EdneParser::from_utf8(String::new()).with_layout(layout),
+            encoding: Encoding::Latin1,
+            file_name: None,
+            buffer: Vec::new(),
+            line_number: 0,
+            offset: 0,
+            line_offset: 0,
+        }
+    }
+
+    pub(crate) fn set_encoding(&mut self, encoding: Encoding) {
+        self.encoding = encoding;
+    }
+
+    pub(crate) fn set_file_name(&mut self, file_name: String) {
+        self.file_name = Some(file_name);
+    }
+
+    /// Accounts for a line of `read` bytes just read into the buffer and
+    /// strips its line ending.
+    ///
+    /// Returns `false` if the line is blank and must be skipped.
+    pub(crate) fn end_line(&mut self, read: usize) -> bool {
+        self.line_number += 1;
+        self.line_offset = self.offset;
+        self.offset += read;
+
+        while matches!(self.buffer.last(), Some(b'\n' | b'\r')) {
+            self.buffer.pop();
+        }
+        !self.buffer.iter().all(u8::is_ascii_whitespace)
+    }
+
+    /// Decodes the buffered line and parses it into a record.
+    pub(crate) fn parse<T: Record>(&self) -> Result<T, ParseError> {
+        let encoding = match self.encoding {
+            Encoding::Auto => Encoding::detect(&self.buffer),
+            other => other,
+        };
+        let line = encoding.decode(&self.buffer)?;
+        T::parse_line(&self.parser, &line, self.line_number)
+            .map_err(|e| self.locate(e, T::FIELDS, encoding, &line))
+    }
+
+    /// Attaches the location of the current line to `error`.
+    fn locate(
+        &self,
+        error: ParseError,
+        fields: &[&str],
+        encoding: Encoding,
+        line: &str,
+    ) -> ParseError {
+        let span = SourceSpan::locate(
+            &error,
+            fields,
+            self.parser.layout(),
+            encoding,
+            line,
+            self.line_offset,
+            self.file_name.as_deref(),
+        );
+        match span {
+            Some(span) => error.with_span(span),
+            None => error,
+        }
+    }
+}
+
+/// Iterator over the records of an ISO-8859-1 encoded eDNE file.
+///
+/// Created by [`stream`] and [`stream_with_layout`]. Empty lines are
+/// skipped; line numbers in errors refer to the original file.
+pub struct RecordStream<T, R> {
+    reader: R,
+    state: LineState,
+    done: bool,
+    _record: PhantomData<fn() -> T>,
+}
+
+impl<T: Record, R: BufRead> RecordStream<T, R> {
+    /// Creates a stream reading `reader` with the given layout.
+    pub fn new(reader: R, layout: Layout) -> Self {
+        Self {
+            reader,
+            state: LineState::new(layout),
+            done: false,
+            _record: PhantomData,
+        }
+    }
+
+    /// Sets the encoding of the input (ISO-8859-1 by default).
+    ///
+    /// With `Encoding::Auto` the encoding is detected line by line.
+    pub fn with_encoding(mut self, encoding: Encoding) -> Self {
+        self.state.set_encoding(encoding);
+        self
+    }
+
+    /// Sets the file name reported in error spans.
+    pub fn with_file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.state.set_file_name(file_name.into());
+        self
+    }
+
+    /// Reads the next non-empty line into the buffer.
+    ///
+    /// Returns `Ok(false)` at end of input.
+    fn read_line(&mut self) -> Result<bool, ParseError> {
+        loop {
+            self.state.buffer.clear();
+            let read =
+                self.reader.read_until(b'\n', &mut self.state.buffer)?;
+            if read == 0 {
+                return Ok(false);
+            }
+            if self.state.end_line(read) {
+                return Ok(true);
+            }
+        }
+    }
+}
+
+impl<T: Record, R: BufRead> Iterator for RecordStream<T, R> {
+    type Item = Result<T, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.read_line() {
+            Ok(true) => Some(self.state.parse()),
+            Ok(false) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Streams records of type `T` from an ISO-8859-1 encoded reader using the
+/// '@' delimited layout.
+///
+/// # Example
+///
+/// ```rust
+/// use edne::{Neighborhood, parser::stream};
+///
+/// let data = "55400@AC@16@Loteamento Jaguar@Lot Jaguar\n".as_bytes();
+/// for neighborhood in stream::<Neighborhood, _>(data) {
+///     println!("{}", neighborhood.unwrap().name);
+/// }
+/// ```
+pub fn stream<T: Record, R: BufRead>(reader: R) -> RecordStream<T, R> {
+    RecordStream::new(reader, Layout::default())
+}
+
+/// Streams records of type `T` from an ISO-8859-1 encoded reader using the
+/// given layout.
+pub fn stream_with_layout<T: Record, R: BufRead>(
+    reader: R,
+    layout: Layout,
+) -> RecordStream<T, R> {
+    RecordStream::new(reader, layout)
+}
+
+/// Parses every record of a reader in `encoding`, passing each one to
+/// `insert`.
+///
+/// With `Encoding::Auto` the encoding is detected line by line.
+///
+/// # Errors
+///
+/// Returns `ParseError` if reading fails or any line has invalid data.
+pub(crate) fn parse_reader<T, R, F>(
+    reader: R,
+    encoding: Encoding,
+    mut insert: F,
+) -> Result<(), ParseError>
+where
+    T: Record,
+    R: Read,
+    F: FnMut(T),
+{
+    let records =
+        stream::<T, _>(BufReader::new(reader)).with_encoding(encoding);
+    for record in records {
+        insert(record?);
+    }
+    Ok(())
+}
+
+/// Parses borrowed records of type `T` from the content of `parser`.
+///
+/// Text fields point into the decoded buffer owned by `parser`, so no
+/// string is allocated per record.
+///
+/// # Example
+///
+/// ```rust
+/// use edne::{NeighborhoodRef, parser::{base::EdneParser, records_ref}};
+///
+/// let parser = EdneParser::from_utf8(
+///     "55400@AC@16@Loteamento Jaguar@Lot Jaguar".to_string(),
+/// );
+/// for neighborhood in records_ref::<NeighborhoodRef>(&parser) {
+///     println!("{}", neighborhood.unwrap().name);
+/// }
+/// ```
+pub fn records_ref<'a, T: RecordRef<'a>>(
+    parser: &'a EdneParser,
+) -> impl Iterator<Item = Result<T, ParseError>> + 'a {
+    parser.located_lines().map(move |line| {
+        T::parse_line(parser, line.text, line.number).map_err(|e| {
+            let fields = <T::Owned as Record>::FIELDS;
+            parser.locate(e, fields, line.text, line.offset)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Address, AddressId, AddressRef, Neighborhood};
+
+    #[test]
+    fn stream_records() {
+        let data = b"\
+1@AC@16@47@@Nelson Mesquita@@69918703@Rua@S@R Nelson Mesquita\r
+\r
+1004886@AC@16@32@@Manoel Cez\xe1rio@@69900816@Travessa@S@Tv Manoel Cez\xe1rio\r
+";
+        let addresses: Vec<Address> =
+            stream(&data[..]).collect::<Result<_, _>>().unwrap();
+
+        assert_eq!(addresses.len(), 2);
+        assert_eq!(addresses[0].id, AddressId::new(1));
+        assert_eq!(addresses[1].name, "Manoel Cezário");
+        assert_eq!(
+            addresses[1].abbreviated_name.as_deref(),
+            Some("Tv Manoel Cezário")
+        );
+    }
+
+    #[test]
+    fn stream_reports_original_line_number() {
+        let data =
+            b"55400@AC@16@Loteamento Jaguar@Lot Jaguar\n\nabc@AC@16@X@\n";
+        let mut records = stream::<Neighborhood, _>(&data[..]);
+
+        assert!(records.next().unwrap().is_ok());
+        match records.next().unwrap().unwrap_err() {
+            ParseError::InvalidValue { line_number, .. } => {
+                assert_eq!(line_number, 3);
+            }
+            _ => panic!("Expected InvalidValue error"),
+        }
+        assert!(records.next().is_none());
+    }
+
+    #[test]
+    fn stream_fixed_width() {
+        let data = b"00055400AC00000016Loteamento Jaguar        Lot Jaguar";
+        let layout = Layout::fixed_width([0, 8, 10, 18, 43]);
+        let records: Vec<Neighborhood> = stream_with_layout(&data[..], layout)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].name, "Loteamento Jaguar");
+    }
+
+    #[test]
+    fn records_ref_borrow_parser_content() {
+        let parser = EdneParser::from_utf8(
+            "1@AC@16@47@@Nelson Mesquita@@69918703@Rua@S@R Nelson Mesquita"
+                .to_string(),
+        );
+        let addresses: Vec<AddressRef> =
+            records_ref(&parser).collect::<Result<_, _>>().unwrap();
+
+        assert_eq!(addresses.len(), 1);
+        assert_eq!(addresses[0].name, "Nelson Mesquita");
+        assert_eq!(addresses[0].complement, None);
+        assert!(parser.content().contains(addresses[0].street_type));
+
+        let owned = addresses[0].to_owned();
+        assert_eq!(owned.id, AddressId::new(1));
+        assert_eq!(owned.name, "Nelson Mesquita");
+    }
+
+    #[test]
+    fn stream_with_encoding() {
+        let data = "55400@AC@16@Conjunto Esperança@Cj Esperança\n".as_bytes();
+        let records: Vec<Neighborhood> = stream(data)
+            .with_encoding(Encoding::Auto)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(records[0].name, "Conjunto Esperança");
+    }
+
+    #[test]
+    fn stream_error_span() {
+        let data =
+            b"55400@AC@16@Esperan\xe7a@Esp\r\n\r\n55401@AC@x@Centro@\r\n";
+        let mut records = stream::<Neighborhood, _>(&data[..])
+            .with_file_name("LOG_BAIRRO.TXT");
+
+        assert!(records.next().unwrap().is_ok());
+        let err = records.next().unwrap().unwrap_err();
+        let span = err.span().unwrap();
+        assert_eq!(span.file_name.as_deref(), Some("LOG_BAIRRO.TXT"));
+        assert_eq!(span.line, 3);
+        assert_eq!(span.column, 10);
+        assert_eq!(span.field_index, Some(2));
+        assert_eq!(span.byte_offset, 38);
+    }
+
+    #[test]
+    fn stream_read_error_keeps_io_error() {
+        struct FailingReader;
+
+        impl Read for FailingReader {
+            fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::ErrorKind::ConnectionReset.into())
+            }
+        }
+
+        let mut records =
+            stream::<Neighborhood, _>(BufReader::new(FailingReader));
+        let err = records.next().unwrap().unwrap_err();
+
+        match &err {
+            ParseError::Io { path: None, source } => {
+                assert_eq!(source.kind(), std::io::ErrorKind::ConnectionReset)
+            }
+            other => panic!("Expected Io error, got {other:?}"),
+        }
+        let source = std::error::Error::source(&err).unwrap();
+        assert!(source.downcast_ref::<std::io::Error>().is_some());
+    }
+}
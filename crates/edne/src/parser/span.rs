@@ -0,0 +1,170 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+//! Source locations of parse errors.
+
+use std::fmt;
+
+use crate::parser::{
+    base::{Layout, ParseError},
+    encoding::Encoding,
+};
+
+/// Location of a parse error in the source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceSpan {
+    /// Name of the source file, when known.
+    pub file_name: Option<String>,
+    /// Byte offset of the failing field from the start of the source, in
+    /// the source encoding.
+    pub byte_offset: usize,
+    /// Line number (1-based).
+    pub line: usize,
+    /// Column of the failing field (1-based, in characters).
+    pub column: usize,
+    /// Index of the failing field (0-based), if the error refers to one.
+    pub field_index: Option<usize>,
+    /// Width of the failing field in characters (at least 1).
+    pub width: usize,
+    /// Text of the offending line.
+    pub source_line: String,
+}
+
+impl SourceSpan {
+    /// Locates `error` in `line`.
+    ///
+    /// `fields` names the columns of the line and `line_offset` is the offset
+    /// of the line in the source, in bytes of `encoding`. Errors without a
+    /// field are located at the whole line.
+    ///
+    /// Returns `None` if the error does not refer to a line or is already
+    /// located.
+    pub(crate) fn locate(
+        error: &ParseError,
+        fields: &[&str],
+        layout: &Layout,
+        encoding: Encoding,
+        line: &str,
+        line_offset: usize,
+        file_name: Option<&str>,
+    ) -> Option<Self> {
+        if error.span().is_some() {
+            return None;
+        }
+        let line_number = match error {
+            ParseError::TooManyErrors { .. } => return None,
+            _ => error.line_number()?,
+        };
+
+        let field_index = error
+            .field_name()
+            .and_then(|name| fields.iter().position(|&field| field == name));
+        let (column, width) = match field_index {
+            Some(index) => layout
+                .field_position(line, index)
+                .unwrap_or((line.chars().count(), 1)),
+            None => (0, line.chars().count()),
+        };
+
+        let prefix: String = line.chars().take(column).collect();
+        Some(Self {
+            file_name: file_name.map(str::to_string),
+            byte_offset: line_offset + encoding.encoded_len(&prefix),
+            line: line_number,
+            column: column + 1,
+            field_index,
+            width: width.max(1),
+            source_line: line.to_string(),
+        })
+    }
+
+    /// Writes the offending line with a caret under the failing field, like
+    /// a compiler diagnostic.
+    pub(crate) fn write_snippet(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let gutter = self.line.to_string().len();
+        let pad = " ".repeat(gutter);
+        let file = self.file_name.as_deref().unwrap_or("<input>");
+
+        writeln!(
+            f,
+            "{} --> {}:{}:{} (byte {})",
+            pad, file, self.line, self.column, self.byte_offset
+        )?;
+        writeln!(f, "{} |", pad)?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(
+            f,
+            "{} | {}{}",
+            pad,
+            " ".repeat(self.column.saturating_sub(1)),
+            "^".repeat(self.width.max(1))
+        )
+    }
+}
+
+impl fmt::Display for SourceSpan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file_name) = &self.file_name {
+            write!(f, "{}:", file_name)?;
+        }
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Snippet<'a>(&'a SourceSpan);
+
+    impl fmt::Display for Snippet<'_> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.write_snippet(f)
+        }
+    }
+
+    fn span() -> SourceSpan {
+        SourceSpan {
+            file_name: Some("LOG_BAIRRO.TXT".to_string()),
+            byte_offset: 94,
+            line: 3,
+            column: 7,
+            field_index: Some(1),
+            width: 2,
+            source_line: "55403@XX@16@Habitasa@Habitasa".to_string(),
+        }
+    }
+
+    #[test]
+    fn span_display() {
+        assert_eq!(span().to_string(), "LOG_BAIRRO.TXT:3:7");
+
+        let without_file = SourceSpan { file_name: None, ..span() };
+        assert_eq!(without_file.to_string(), "3:7");
+    }
+
+    #[test]
+    fn span_snippet() {
+        let expected = "  --> LOG_BAIRRO.TXT:3:7 (byte 94)
+  |
+3 | 55403@XX@16@Habitasa@Habitasa
+  |       ^^";
+        assert_eq!(Snippet(&span()).to_string(), expected);
+    }
+}
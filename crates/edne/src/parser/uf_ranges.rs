@@ -0,0 +1,245 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+use std::{collections::HashMap, str::FromStr};
+
+use crate::{
+    models::{Cep, Uf, uf_cep_range::UfCepRange},
+    parser::{
+        base::{EdneParser, ParseError},
+        collection::Collection,
+        helpers,
+        record_stream::Record,
+    },
+};
+
+/// Expected number of fields in a UF CEP range record.
+const UF_CEP_RANGE_FIELD_COUNT: usize = 3;
+
+/// Collection of UF CEP ranges indexed by their UF.
+#[derive(Debug, Clone)]
+pub struct UfCepRanges(HashMap<Uf, Vec<UfCepRange>>);
+
+impl UfCepRanges {
+    /// Creates a new empty collection.
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Creates a collection with pre-allocated capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(HashMap::with_capacity(capacity))
+    }
+
+    /// Returns the number of ranges.
+    pub fn len(&self) -> usize {
+        self.0.values().map(Vec::len).sum()
+    }
+
+    /// Returns `true` if the collection is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Gets all ranges of a UF.
+    pub fn get(&self, uf: &Uf) -> Option<&[UfCepRange]> {
+        self.0.get(uf).map(Vec::as_slice)
+    }
+
+    /// Inserts a range into the collection.
+    pub fn insert(&mut self, range: UfCepRange) {
+        self.0.entry(range.uf).or_default().push(range);
+    }
+
+    /// Returns an iterator over all ranges.
+    pub fn iter(&self) -> impl Iterator<Item = &UfCepRange> {
+        self.0.values().flatten()
+    }
+
+    /// Returns the UF whose ranges contain `cep`.
+    pub fn uf_for_cep(&self, cep: Cep) -> Option<Uf> {
+        self.iter().find(|range| range.contains(cep)).map(|range| range.uf)
+    }
+}
+
+impl Default for UfCepRanges {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Collection for UfCepRanges {
+    type Record = UfCepRange;
+    const ARCHIVE_ENTRY: &'static str = "LOG_FAIXA_UF.TXT";
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity(capacity)
+    }
+}
+
+impl Extend<UfCepRange> for UfCepRanges {
+    fn extend<I: IntoIterator<Item = UfCepRange>>(&mut self, iter: I) {
+        for record in iter {
+            self.insert(record);
+        }
+    }
+}
+
+impl FromIterator<UfCepRange> for UfCepRanges {
+    fn from_iter<I: IntoIterator<Item = UfCepRange>>(iter: I) -> Self {
+        let mut records = Self::new();
+        records.extend(iter);
+        records
+    }
+}
+
+impl Record for UfCepRange {
+    const FIELDS: &'static [&'static str] =
+        &["UFE_SG", "UFE_CEP_INI", "UFE_CEP_FIM"];
+
+    fn parse_line(
+        parser: &EdneParser,
+        line: &str,
+        line_number: usize,
+    ) -> Result<Self, ParseError> {
+        parse_uf_cep_range_line(parser, line, line_number)
+    }
+}
+
+/// Parses a single LOG_FAIXA_UF line into a `UfCepRange` struct.
+///
+/// # Field order (3 fields):
+/// 1. UFE_SG - UF code
+/// 2. UFE_CEP_INI - First CEP of the range
+/// 3. UFE_CEP_FIM - Last CEP of the range
+fn parse_uf_cep_range_line(
+    parser: &EdneParser,
+    line: &str,
+    line_number: usize,
+) -> Result<UfCepRange, ParseError> {
+    let fields = parser.parse_line_checked(
+        line,
+        UF_CEP_RANGE_FIELD_COUNT,
+        line_number,
+    )?;
+
+    let uf_str = EdneParser::required_field(fields[0], "UFE_SG", line_number)?;
+    let uf = Uf::from_str(&uf_str).map_err(|e| ParseError::InvalidValue {
+        field_name: "UFE_SG",
+        value: uf_str,
+        reason: e.to_string(),
+        source: Some(e.into()),
+        line_number,
+        span: None,
+    })?;
+
+    let (cep_start, cep_end) = helpers::required_cep_range(
+        (fields[1], "UFE_CEP_INI"),
+        (fields[2], "UFE_CEP_FIM"),
+        line_number,
+    )?;
+
+    Ok(UfCepRange { uf, cep_start, cep_end })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_DATA: &str = "\
+AC@69900000@69999999
+AL@57000000@57999999
+AM@69000000@69299999
+AM@69400000@69899999
+DF@70000000@72799999
+DF@73000000@73699999
+GO@72800000@72999999
+GO@73700000@76799999
+SP@01000000@19999999";
+
+    #[test]
+    fn parse_sample_data() {
+        let ranges = UfCepRanges::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        assert_eq!(ranges.len(), 9);
+        assert_eq!(ranges.iter().count(), 9);
+    }
+
+    #[test]
+    fn parse_uf_with_several_ranges() {
+        let ranges = UfCepRanges::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        let df = ranges.get(&Uf::DF).unwrap();
+
+        assert_eq!(df.len(), 2);
+        assert_eq!(df[0].cep_start, Cep::new(70000000));
+        assert_eq!(df[0].cep_end, Cep::new(72799999));
+        assert_eq!(df[1].cep_start, Cep::new(73000000));
+        assert_eq!(df[1].cep_end, Cep::new(73699999));
+    }
+
+    #[test]
+    fn uf_for_cep_found() {
+        let ranges = UfCepRanges::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        assert_eq!(ranges.uf_for_cep(Cep::new(69918703)), Some(Uf::AC));
+        assert_eq!(ranges.uf_for_cep(Cep::new(1310100)), Some(Uf::SP));
+        assert_eq!(ranges.uf_for_cep(Cep::new(72850000)), Some(Uf::GO));
+        assert_eq!(ranges.uf_for_cep(Cep::new(73010000)), Some(Uf::DF));
+    }
+
+    #[test]
+    fn uf_for_cep_not_found() {
+        let ranges = UfCepRanges::from_utf8(SAMPLE_DATA.to_string()).unwrap();
+        assert_eq!(ranges.uf_for_cep(Cep::new(69300000)), None);
+    }
+
+    #[test]
+    fn parse_invalid_field_count() {
+        let invalid = "AC@69900000";
+        let result = UfCepRanges::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::FieldCount { expected, got, .. } => {
+                assert_eq!(expected, 3);
+                assert_eq!(got, 2);
+            }
+            _ => panic!("Expected FieldCount error"),
+        }
+    }
+
+    #[test]
+    fn parse_invalid_uf() {
+        let invalid = "ZZ@69900000@69999999";
+        let result = UfCepRanges::from_utf8(invalid.to_string());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_invalid_cep() {
+        let invalid = "AC@6990000X@69999999";
+        let result = UfCepRanges::from_utf8(invalid.to_string());
+        match result.unwrap_err() {
+            ParseError::InvalidValue { field_name, .. } => {
+                assert_eq!(field_name, "UFE_CEP_INI");
+            }
+            _ => panic!("Expected InvalidValue error"),
+        }
+    }
+
+    #[test]
+    fn parse_inverted_range() {
+        let invalid = "AC@69999999@69900000";
+        let result = UfCepRanges::from_utf8(invalid.to_string());
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,33 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+//! Traits needed to call the collection constructors and delta methods.
+//!
+//! Constructors such as `Localities::from_path` are provided by the
+//! [`Collection`] trait, so it must be in scope to call them:
+//!
+//! ```rust
+//! use edne::{parser::localities::Localities, prelude::*};
+//!
+//! # fn main() -> Result<(), edne::ParseError> {
+//! let data = "16@AC@Rio Branco@@1@M@@Rio Branco@1200401";
+//! let localities = Localities::from_utf8(data.to_string())?;
+//! assert_eq!(localities.len(), 1);
+//! # Ok(())
+//! # }
+//! ```
+
+pub use crate::parser::{Collection, DeltaCollection, Record, RecordRef};
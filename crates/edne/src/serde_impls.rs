@@ -0,0 +1,304 @@
+//
+// Copyright (c) 2025 murilo ijanc' <murilo@ijanc.org>
+//
+// Permission to use, copy, modify, and distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHOR DISCLAIMS ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHOR BE LIABLE FOR
+// ANY SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN
+// ACTION OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF
+// OR IN CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+//
+
+//! `Serialize` and `Deserialize` implementations (`serde` feature).
+//!
+//! Records derive both traits in their own modules. The types below are
+//! written by hand so their representation stays stable:
+//!
+//! - IDs and IBGE codes are numbers, validated when deserialized
+//! - Enums use the code found in the eDNE files (`"SP"`, `"M"`, `"S"`)
+//! - CEPs use the canonical `00000-000` format
+//! - Collections are sequences of records, sorted by key
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::{
+    models::{
+        Address, AddressId, AddressNameVariant, BigUser, BigUserId, Cep,
+        Country, Cpc, CpcId, CpcPostBoxRange, DeltaOperation,
+        IbgeMunicipalityCode, Locality, LocalityCepRange, LocalityId,
+        LocalityNameVariant, LocalityRangeType, LocalitySituation,
+        LocalityType, Neighborhood, NeighborhoodCepRange, NeighborhoodId,
+        NeighborhoodNameVariant, NumberSection, OperationalUnit,
+        OperationalUnitId, OperationalUnitPostBoxRange, PostBoxIndicator,
+        Region, SectionSide, StreetId, StreetType, StreetTypeIndicator, Uf,
+        UfCepRange,
+    },
+    parser::{
+        address_variants::AddressNameVariants, addresses::Addresses,
+        big_users::BigUsers, countries::Countries,
+        cpc_ranges::CpcPostBoxRanges, cpcs::Cpcs, localities::Localities,
+        locality_ranges::LocalityCepRanges,
+        locality_variants::LocalityNameVariants,
+        neighborhood_ranges::NeighborhoodCepRanges,
+        neighborhood_variants::NeighborhoodNameVariants,
+        neighborhoods::Neighborhoods, number_sections::NumberSections,
+        operational_unit_ranges::OperationalUnitPostBoxRanges,
+        operational_units::OperationalUnits, uf_ranges::UfCepRanges,
+    },
+};
+
+/// Serializes through `get()` and deserializes through `TryFrom<u32>`.
+macro_rules! serde_u32 {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    serializer.serialize_u32(self.get())
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Self, D::Error> {
+                    let value = u32::deserialize(deserializer)?;
+                    Self::try_from(value).map_err(de::Error::custom)
+                }
+            }
+        )*
+    };
+}
+
+/// Serializes through `Display` and deserializes through `FromStr`.
+macro_rules! serde_str {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(
+                    deserializer: D,
+                ) -> Result<Self, D::Error> {
+                    let value = String::deserialize(deserializer)?;
+                    value.parse().map_err(de::Error::custom)
+                }
+            }
+        )*
+    };
+}
+
+/// Serializes a collection as a sequence of its records.
+///
+/// Keyed collections are given as `Collection(Record)` and grouped ones,
+/// whose `iter` yields records only, as `Collection(Record, key_field)`.
+macro_rules! serde_collection {
+    (@de $collection:ty, $record:ty) => {
+        impl<'de> Deserialize<'de> for $collection {
+            fn deserialize<D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                let mut collection = Self::new();
+                for record in Vec::<$record>::deserialize(deserializer)? {
+                    collection.insert(record);
+                }
+                Ok(collection)
+            }
+        }
+    };
+    (@ser $collection:ty) => {
+        impl Serialize for $collection {
+            fn serialize<S: Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                let mut records: Vec<_> = self.iter().collect();
+                records.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+                serializer.collect_seq(records.into_iter().map(|(_, r)| r))
+            }
+        }
+    };
+    (@ser $collection:ty, $key:ident) => {
+        impl Serialize for $collection {
+            fn serialize<S: Serializer>(
+                &self,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                let mut records: Vec<_> = self.iter().collect();
+                records.sort_by_key(|record| record.$key);
+                serializer.collect_seq(records)
+            }
+        }
+    };
+    ($($collection:ty => $record:ty $(, $key:ident)?;)*) => {
+        $(
+            serde_collection!(@ser $collection $(, $key)?);
+            serde_collection!(@de $collection, $record);
+        )*
+    };
+}
+
+serde_u32! {
+    AddressId,
+    BigUserId,
+    CpcId,
+    IbgeMunicipalityCode,
+    LocalityId,
+    NeighborhoodId,
+    OperationalUnitId,
+    StreetId,
+}
+
+serde_str! {
+    Cep,
+    DeltaOperation,
+    LocalityRangeType,
+    LocalitySituation,
+    LocalityType,
+    PostBoxIndicator,
+    Region,
+    SectionSide,
+    StreetType,
+    StreetTypeIndicator,
+    Uf,
+}
+
+serde_collection! {
+    Addresses => Address;
+    BigUsers => BigUser;
+    Countries => Country;
+    Cpcs => Cpc;
+    Localities => Locality;
+    Neighborhoods => Neighborhood;
+    NumberSections => NumberSection;
+    OperationalUnits => OperationalUnit;
+    AddressNameVariants => AddressNameVariant, address_id;
+    CpcPostBoxRanges => CpcPostBoxRange, cpc_id;
+    LocalityCepRanges => LocalityCepRange, locality_id;
+    LocalityNameVariants => LocalityNameVariant, locality_id;
+    NeighborhoodCepRanges => NeighborhoodCepRange, neighborhood_id;
+    NeighborhoodNameVariants =>
+        NeighborhoodNameVariant, neighborhood_id;
+    OperationalUnitPostBoxRanges =>
+        OperationalUnitPostBoxRange, operational_unit_id;
+    UfCepRanges => UfCepRange, uf;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::collection::Collection;
+    use serde_json::json;
+
+    const LOCALITIES: &str = "\
+16@AC@Rio Branco@@1@M@@Rio Branco@1200401
+13@AC@Placido de Castro@69928000@0@M@@Placido Castro@1200385";
+
+    #[test]
+    fn locality_serializes_with_stable_representations() {
+        let localities =
+            Localities::from_utf8(LOCALITIES.to_string()).unwrap();
+        let locality = localities.get(&LocalityId::new(13)).unwrap();
+
+        let value = serde_json::to_value(locality).unwrap();
+        assert_eq!(value["id"], json!(13));
+        assert_eq!(value["uf"], json!("AC"));
+        assert_eq!(value["cep"], json!("69928-000"));
+        assert_eq!(value["situation"], json!("0"));
+        assert_eq!(value["locality_type"], json!("M"));
+        assert_eq!(value["ibge_code"], json!(1200385));
+
+        let back: Locality = serde_json::from_value(value).unwrap();
+        assert_eq!(&back, locality);
+    }
+
+    #[test]
+    fn collection_serializes_as_sorted_sequence() {
+        let localities =
+            Localities::from_utf8(LOCALITIES.to_string()).unwrap();
+
+        let value = serde_json::to_value(&localities).unwrap();
+        let ids: Vec<_> = value
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|locality| locality["id"].clone())
+            .collect();
+        assert_eq!(ids, [json!(13), json!(16)]);
+
+        let back: Localities = serde_json::from_value(value).unwrap();
+        assert_eq!(back.len(), localities.len());
+        for (id, locality) in localities.iter() {
+            assert_eq!(back.get(id), Some(locality));
+        }
+    }
+
+    #[test]
+    fn grouped_collection_serializes_sorted_by_key() {
+        let data = "16@69900001@69923999@T\n13@69928000@69929999@T\n\
+                    16@69900001@69923999@C";
+        let ranges = LocalityCepRanges::from_utf8(data.to_string()).unwrap();
+
+        let json = serde_json::to_string(&ranges).unwrap();
+        assert_eq!(
+            json,
+            "[{\"locality_id\":13,\"cep_start\":\"69928-000\",\
+             \"cep_end\":\"69929-999\",\"range_type\":\"T\"},\
+             {\"locality_id\":16,\"cep_start\":\"69900-001\",\
+             \"cep_end\":\"69923-999\",\"range_type\":\"T\"},\
+             {\"locality_id\":16,\"cep_start\":\"69900-001\",\
+             \"cep_end\":\"69923-999\",\"range_type\":\"C\"}]"
+        );
+
+        let back: LocalityCepRanges = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.iter().count(), 3);
+    }
+
+    #[test]
+    fn enums_and_newtypes_round_trip() {
+        let value =
+            json!(["SP", "Centro-Oeste", "Av.", "S", "UPD", "01310100"]);
+        let (uf, region, street_type, indicator, operation, cep): (
+            Uf,
+            Region,
+            StreetType,
+            StreetTypeIndicator,
+            DeltaOperation,
+            Cep,
+        ) = serde_json::from_value(value).unwrap();
+        assert_eq!(uf, Uf::SP);
+        assert_eq!(region, Region::CentroOeste);
+        assert_eq!(street_type, StreetType::Avenida);
+        assert_eq!(indicator, StreetTypeIndicator::Yes);
+        assert_eq!(operation, DeltaOperation::Update);
+        assert_eq!(cep, Cep::new(1310100));
+
+        let json = serde_json::to_value((street_type, cep)).unwrap();
+        assert_eq!(json, json!(["Avenida", "01310-100"]));
+    }
+
+    #[test]
+    fn invalid_values_are_rejected() {
+        assert!(serde_json::from_value::<LocalityId>(json!(0)).is_err());
+        assert!(serde_json::from_value::<Uf>(json!("XX")).is_err());
+        assert!(serde_json::from_value::<Cep>(json!("0131-0100")).is_err());
+        assert!(
+            serde_json::from_value::<IbgeMunicipalityCode>(json!(1200402))
+                .is_err()
+        );
+    }
+}